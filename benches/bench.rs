@@ -30,3 +30,95 @@ fn bench_type_j_celcius(b: &mut Bencher) {
             thermocouple.sense_temperature(Millivolts(2.0));
     });
 }
+///
+/// Type T below 0ºC, which has the longest E(T) polynomial. Compare
+/// with and without the `estrin` feature.
+///
+#[bench]
+fn bench_type_t_below_0_voltage(b: &mut Bencher) {
+    let thermocouple = thermocouple::TType::new();
+
+    b.iter(|| {
+        test::black_box(
+            thermocouple
+                .sense_voltage(test::black_box(Celsius(-150.0))),
+        )
+    });
+}
+///
+/// Type K, batches of 1000 readings
+///
+#[bench]
+fn bench_type_k_celcius_loop(b: &mut Bencher) {
+    let thermocouple = thermocouple::KType::new();
+    let voltages = [Millivolts(2.0); 1000];
+    let mut temperatures = [Celsius(0.0); 1000];
+
+    b.iter(|| {
+        for (voltage, temperature) in
+            voltages.iter().zip(temperatures.iter_mut())
+        {
+            *temperature = thermocouple.sense_temperature(*voltage);
+        }
+    });
+}
+#[bench]
+fn bench_type_k_celcius_slice(b: &mut Bencher) {
+    let thermocouple = thermocouple::KType::new();
+    let voltages = [Millivolts(2.0); 1000];
+    let mut temperatures = [Celsius(0.0); 1000];
+
+    b.iter(|| {
+        thermocouple
+            .sense_temperature_slice(&voltages, &mut temperatures);
+    });
+}
+///
+/// One of each type, as for a panel of probes
+///
+#[bench]
+fn bench_all_types_celcius(b: &mut Bencher) {
+    let voltage_by_type = [
+        (ThermocoupleType::B, Millivolts(2.0)),
+        (ThermocoupleType::E, Millivolts(2.0)),
+        (ThermocoupleType::J, Millivolts(2.0)),
+        (ThermocoupleType::K, Millivolts(2.0)),
+        (ThermocoupleType::N, Millivolts(2.0)),
+        (ThermocoupleType::R, Millivolts(2.0)),
+        (ThermocoupleType::S, Millivolts(2.0)),
+        (ThermocoupleType::T, Millivolts(2.0)),
+    ];
+    let mut temperatures = [Celsius(0.0); 8];
+
+    b.iter(|| {
+        thermocouple::sense_all_types(
+            &voltage_by_type,
+            &mut temperatures,
+        );
+    });
+}
+///
+/// Type K, with a reference junction temperature for every reading.
+/// Compare recomputing E(T) for the reference with caching it.
+///
+#[bench]
+fn bench_type_k_celcius_with_reference(b: &mut Bencher) {
+    let thermocouple = thermocouple::KType::new();
+
+    b.iter(|| {
+        let _: Celsius = thermocouple.sense_temperature_with_reference(
+            Millivolts(2.0),
+            test::black_box(Celsius(20.0)),
+        );
+    });
+}
+#[bench]
+fn bench_type_k_celcius_reference_cache(b: &mut Bencher) {
+    let mut cache = thermocouple::KType::new()
+        .with_reference_cache(Celsius(20.0), Celsius(0.1));
+
+    b.iter(|| {
+        cache.update(test::black_box(Celsius(20.0)));
+        let _: Celsius = cache.sense_temperature(Millivolts(2.0));
+    });
+}
@@ -1,6 +1,6 @@
 #![deny(unsafe_code)]
 
-use thermocouple::{prelude::*, JType};
+use thermocouple::prelude::*;
 
 fn main() {
     println!("Type J thermocouple with reference junction at 0ºC");
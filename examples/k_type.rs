@@ -1,6 +1,6 @@
 #![deny(unsafe_code)]
 
-use thermocouple::{prelude::*, KType};
+use thermocouple::prelude::*;
 
 fn main() {
     println!("Type K thermocouple with reference junction at 25ºC");
@@ -1,6 +1,9 @@
 //! B-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const B_TYPE_E_BELOW_630_615: [FP; 7] = [
     0.000000000000E+00,
     -0.246508183460E-03,
@@ -10,6 +13,7 @@ const B_TYPE_E_BELOW_630_615: [FP; 7] = [
     -0.169445292400E-14,
     0.629903470940E-18,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const B_TYPE_E_ABOVE_630_615: [FP; 9] = [
     -0.389381686210E+01,
     0.285717474700E-01,
@@ -22,6 +26,7 @@ const B_TYPE_E_ABOVE_630_615: [FP; 9] = [
     -0.937913302890E-24,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const B_TYPE_T0: [FP; 9] = [
     9.8423321E+01,
     6.9971500E+02,
@@ -33,6 +38,7 @@ const B_TYPE_T0: [FP; 9] = [
     2.9886750E+01,
     -2.4742860E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const B_TYPE_T1: [FP; 9] = [
     2.1315071E+02,
     2.8510504E+02,
@@ -45,76 +51,247 @@ const B_TYPE_T1: [FP; 9] = [
     -2.4878585E-06,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const B_TYPE_E_BELOW_630_615: [FP; 4] = [
+    -0.6407440905E-04,
+    -0.2398061970E-03,
+    0.5794866381E-05,
+    -0.6962382070E-09,
+];
+#[cfg(feature = "reduced-order")]
+const B_TYPE_E_ABOVE_630_615: [FP; 5] = [
+    -0.4070444520E+00,
+    0.1563948238E-02,
+    0.2860760746E-05,
+    0.1422905184E-08,
+    -0.6080918312E-12,
+];
+#[cfg(feature = "reduced-order")]
+const B_TYPE_T0: [FP; 6] = [
+    0.1186726177E+03,
+    0.5394413704E+03,
+    -0.3471463966E+03,
+    0.1875072514E+03,
+    -0.5564800507E+02,
+    0.6732795928E+01,
+];
+#[cfg(feature = "reduced-order")]
+const B_TYPE_T1: [FP; 6] = [
+    0.2732971319E+03,
+    0.2158398387E+03,
+    -0.2027388969E+02,
+    0.1762946220E+01,
+    -0.8535508318E-01,
+    0.1778456167E-02,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &B_TYPE_E_BELOW_630_615,
+    &B_TYPE_E_ABOVE_630_615,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &B_TYPE_T0,
+    &B_TYPE_T1,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(B_TYPE_E_BELOW_630_615[0] == 0.0);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0], E_STEP_MAX));
+
+    let [v0] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(0.0), Celsius(1820.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(0.000), Millivolts(13.820));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(0.291), Millivolts(13.820));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(630.615)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 1] =
+    [Millivolts(2.431)];
+
+/// Temperature at which E(T) is zero. E(T) is also zero at about
+/// 41ºC, as the Seebeck coefficient is negative below 21ºC.
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(600.0), Millivolts(1.792)),
+    (Celsius(1000.0), Millivolts(4.834)),
+    (Celsius(1600.0), Millivolts(11.263)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a B-Type thermocouple in the range 0ºC to
 /// 1820ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= 0.0);
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1820.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
 
-    let e = match t > 630.615 {
+    e_unchecked(t)
+}
+
+/// Evaluate E(T) for a B-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // 0ºC -> 630.615ºC
-            const C: [FP; 7] = B_TYPE_E_BELOW_630_615;
+            let c = B_TYPE_E_BELOW_630_615;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
+            polyval(c, t)
         }
         _ => {
             // 630.615ºC -> 1820ºC
-            const C: [FP; 9] = B_TYPE_E_ABOVE_630_615;
+            let c = B_TYPE_E_ABOVE_630_615;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a B-Type thermocouple in
+/// the range 0ºC to 1820ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// B-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(B_TYPE_E_BELOW_630_615, t),
+        _ => polyval_with_derivative(B_TYPE_E_ABOVE_630_615, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a B-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::Two if (600.0..=1700.0).contains(&t) => {
+            deviation(t, 0.0, 0.0025)
+        }
+        ToleranceClass::Three if (600.0..=1700.0).contains(&t) => {
+            deviation(t, 4.0, 0.005)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// IEC 60584-3 defines only compensating cable for B-Type
+/// thermocouples, not extension wire
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> = None;
+
+/// Always returns `None`, as IEC 60584-3 defines only compensating
+/// cable for B-Type thermocouples, not extension wire.
+pub fn extension_tolerance(_class: ToleranceClass) -> Option<Celsius> {
+    None
+}
+
 /// Evaluate T for a B-Type thermocouple given E(T) in the range
-/// 0.291mV to 13.280mV, where T is in Celsius and E(T) is in millivolts.
+/// 0.291mV to 13.820mV, where T is in Celsius and E(T) is in millivolts.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= 0.291 - TOL);
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 13.82 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
+
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a B-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
 
-    let c = match e < 2.431 {
+/// Evaluate T for a B-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0] = INVERSE_BREAKPOINTS;
+    let c = match e < b0.0 {
         true => B_TYPE_T0,
         false => B_TYPE_T1,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -124,8 +301,12 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 0.7;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
-        "../nist/type_b.tab.rs", 0, 1820, |t| t >= 250 && t < 1820
+        "../nist/type_b.tab.rs", 0, 1820, |t| (250..1820).contains(&t)
     }
 }
@@ -1,6 +1,9 @@
 //! E-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const E_TYPE_E_BELOW_0: [FP; 14] = [
     0.000000000000E+00,
     0.586655087080E-01,
@@ -17,6 +20,7 @@ const E_TYPE_E_BELOW_0: [FP; 14] = [
     -0.558273287210E-25,
     -0.346578420130E-28,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const E_TYPE_E_ABOVE_0: [FP; 11] = [
     0.000000000000E+00,
     0.586655087100E-01,
@@ -31,6 +35,7 @@ const E_TYPE_E_ABOVE_0: [FP; 11] = [
     0.359608994810E-27,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const E_TYPE_T0: [FP; 10] = [
     0.0000000E+00,
     1.6977288E+01,
@@ -43,6 +48,7 @@ const E_TYPE_T0: [FP; 10] = [
     -1.1564890E-05,
     0.0000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const E_TYPE_T1: [FP; 10] = [
     0.0000000E+00,
     1.7057035E+01,
@@ -56,99 +62,263 @@ const E_TYPE_T1: [FP; 10] = [
     -3.2447087E-14,
 ];
 
-/// Evaluate E(T) for a E-Type thermocouple in the range 0ºC to
-/// 1820ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -270.0);
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const E_TYPE_E_BELOW_0: [FP; 4] = [
+    -0.3720255183E-01,
+    0.5580771536E-01,
+    0.2053790621E-04,
+    -0.1916033920E-06,
+];
+#[cfg(feature = "reduced-order")]
+const E_TYPE_E_ABOVE_0: [FP; 5] = [
+    -0.6467214906E-02,
+    0.5877002406E-01,
+    0.5079927199E-04,
+    -0.4795330043E-07,
+    0.1477591902E-10,
+];
+#[cfg(feature = "reduced-order")]
+const E_TYPE_T0: [FP; 6] = [
+    0.1572277644E+00,
+    0.1768037335E+02,
+    0.3517293781E+00,
+    0.2463836660E+00,
+    0.3568129739E-01,
+    0.2211083702E-02,
+];
+#[cfg(feature = "reduced-order")]
+const E_TYPE_T1: [FP; 6] = [
+    0.7253473563E+00,
+    0.1660383890E+02,
+    -0.1687221082E+00,
+    0.3278248693E-02,
+    -0.3157690480E-04,
+    0.1268445656E-06,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &E_TYPE_E_BELOW_0,
+    &E_TYPE_E_ABOVE_0,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &E_TYPE_T0,
+    &E_TYPE_T1,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    E_TYPE_E_BELOW_0[0] == 0.0
+        && E_TYPE_E_ABOVE_0[0] == 0.0
+        && E_TYPE_T0[0] == 0.0
+        && E_TYPE_T1[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0], E_STEP_MAX));
+
+    let [v0] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-270.0), Celsius(1000.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-9.835), Millivolts(76.373));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-8.825), Millivolts(76.373));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(0.0)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 1] =
+    [Millivolts(0.0)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(-100.0), Millivolts(-5.237)),
+    (Celsius(300.0), Millivolts(21.036)),
+    (Celsius(800.0), Millivolts(61.017)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
+/// Evaluate E(T) for a E-Type thermocouple in the range -270ºC to
+/// 1000ºC, where T is in Celsius and E(T) is in millivolts.
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1000.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
 
-    let e = match t > 0.0 {
+    e_unchecked(t)
+}
+
+/// Evaluate E(T) for a E-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // -270ºC -> 0ºC
-            const C: [FP; 14] = E_TYPE_E_BELOW_0;
+            let c = E_TYPE_E_BELOW_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
-                + C[10] * t * t * t * t * t * t * t * t * t * t
-                + C[11] * t * t * t * t * t * t * t * t * t * t * t
-                + C[12] * t * t * t * t * t * t * t * t * t * t * t * t
-                + C[13]
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
+            polyval(c, t)
         }
         _ => {
             // 0ºC -> 1000ºC
-            const C: [FP; 11] = E_TYPE_E_ABOVE_0;
+            let c = E_TYPE_E_ABOVE_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
-                + C[10] * t * t * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a E-Type thermocouple in
+/// the range -270ºC to 1000ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// E-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(E_TYPE_E_BELOW_0, t),
+        _ => polyval_with_derivative(E_TYPE_E_ABOVE_0, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a E-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (-40.0..=800.0).contains(&t) => {
+            deviation(t, 1.5, 0.004)
+        }
+        ToleranceClass::Two if (-40.0..=900.0).contains(&t) => {
+            deviation(t, 2.5, 0.0075)
+        }
+        ToleranceClass::Three if (-200.0..=40.0).contains(&t) => {
+            deviation(t, 2.5, 0.015)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// Range of temperatures over which EX extension wire is defined by
+/// IEC 60584-3
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> =
+    Some((Celsius(-25.0), Celsius(200.0)));
+
+/// Evaluate the permissible deviation for EX extension wire of the given
+/// IEC 60584-3 tolerance class, within its temperature range. Returns
+/// `None` if the class is not defined.
+pub fn extension_tolerance(class: ToleranceClass) -> Option<Celsius> {
+    match class {
+        ToleranceClass::One => Some(Celsius(1.5)),
+        ToleranceClass::Two => Some(Celsius(2.5)),
+        _ => None,
+    }
+}
+
 /// Evaluate T for a E-Type thermocouple given E(T) in the range
 /// -8.825mV to 76.373mV, where T is in Celsius and E(T) is in millivolts.
+///
+/// NIST does not define an inverse function below -200ºC, so E(T) from
+/// -270ºC to -200ºC is out of range even though E(T) is defined there.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -8.825 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 76.373 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match e < 0.0 {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a E-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a E-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0] = INVERSE_BREAKPOINTS;
+    let c = match e < b0.0 {
         true => E_TYPE_T0,
         false => E_TYPE_T1,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e
-        + c[9] * e * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -158,8 +328,12 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 1.0;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
-        "../nist/type_e.tab.rs", -270, 1000, |t| t >= -200 && t <= 1000
+        "../nist/type_e.tab.rs", -270, 1000, |t| (-200..=1000).contains(&t)
     }
 }
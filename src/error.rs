@@ -0,0 +1,336 @@
+//! Errors for thermocouple operations.
+
+use crate::{Celsius, Kelvin, Millivolts, ThermocoupleType, FP};
+use core::fmt;
+use core::ops::Sub;
+
+/// A value lies outside the range over which a thermocouple type is
+/// defined. For a thermoelectric potential this is the range of the
+/// inverse function, and for a temperature (`RangeError<Celsius>`) it
+/// is the range of the reference function.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct RangeError<T = Millivolts> {
+    /// The offending value. A thermoelectric potential includes the
+    /// reference junction potential, and a temperature has any
+    /// calibration removed.
+    pub value: T,
+    /// Lower bound of the range
+    pub min: T,
+    /// Upper bound of the range
+    pub max: T,
+}
+
+impl<T> RangeError<T>
+where
+    T: Copy + PartialOrd + Sub<Output = T> + From<FP>,
+{
+    /// Returns how far the value lies beyond the nearest bound of the
+    /// range. This is negative below the range, positive above it and
+    /// zero within it. A small overshoot suggests a transient, while a
+    /// large one suggests a fault such as an open circuit.
+    pub fn overshoot(&self) -> T {
+        if self.value < self.min {
+            self.value - self.min
+        } else if self.value > self.max {
+            self.value - self.max
+        } else {
+            T::from(0.0)
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for RangeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is outside the range {} to {}",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+/// A Type B thermoelectric potential is too small to determine the
+/// temperature. E(T) has a minimum near 21ºC, so is double-valued
+/// below about 41ºC, and NIST does not define the inverse function
+/// below 250ºC.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AmbiguousVoltageError {
+    /// The offending potential, including the reference junction
+    /// potential
+    pub value: Millivolts,
+    /// Smallest potential that gives an unambiguous temperature
+    pub min: Millivolts,
+}
+
+impl fmt::Display for AmbiguousVoltageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} is ambiguous for a B-Type thermocouple, below {}",
+            self.value, self.min
+        )
+    }
+}
+
+/// A string did not name a supported thermocouple type.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ParseThermocoupleError;
+
+impl fmt::Display for ParseThermocoupleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown thermocouple type")
+    }
+}
+
+/// A temperature lies below absolute zero.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct BelowAbsoluteZeroError {
+    /// The offending temperature
+    pub value: Kelvin,
+}
+
+impl fmt::Display for BelowAbsoluteZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is below absolute zero", self.value)
+    }
+}
+
+/// The reference functions for a thermocouple type did not reproduce
+/// known values, for example because the coefficients are corrupted.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SelfTestError {
+    /// The thermocouple type that failed
+    pub kind: ThermocoupleType,
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "self test failed for {:?}-Type thermocouple",
+            self.kind
+        )
+    }
+}
+
+/// There are more values than fit in a fixed capacity buffer.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CapacityError {
+    /// Number of values
+    pub len: usize,
+    /// Capacity of the buffer
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} values do not fit in a capacity of {}",
+            self.len, self.capacity
+        )
+    }
+}
+
+/// Any error from this crate, for code that handles them all the same
+/// way.
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ThermocoupleError {
+    /// A thermoelectric potential is out of range
+    Range(RangeError),
+    /// A thermoelectric potential is within the range of the reference
+    /// function, but outside the range where NIST defines the inverse
+    /// function. The error's `min` and `max` bound the inverse function.
+    InverseUndefined(RangeError),
+    /// A temperature is out of range
+    TemperatureRange(RangeError<Celsius>),
+    /// A Type B potential is in the ambiguous region
+    Ambiguous(AmbiguousVoltageError),
+    /// A string did not name a supported thermocouple type
+    Parse(ParseThermocoupleError),
+    /// A temperature lies below absolute zero
+    BelowAbsoluteZero(BelowAbsoluteZeroError),
+    /// The self test failed
+    SelfTest(SelfTestError),
+    /// There are more values than fit in a buffer
+    Capacity(CapacityError),
+}
+
+impl fmt::Display for ThermocoupleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ThermocoupleError::Range(e) => e.fmt(f),
+            ThermocoupleError::InverseUndefined(e) => write!(
+                f,
+                "{} has no inverse function, which is defined from {} to {}",
+                e.value, e.min, e.max
+            ),
+            ThermocoupleError::TemperatureRange(e) => e.fmt(f),
+            ThermocoupleError::Ambiguous(e) => e.fmt(f),
+            ThermocoupleError::Parse(e) => e.fmt(f),
+            ThermocoupleError::BelowAbsoluteZero(e) => e.fmt(f),
+            ThermocoupleError::SelfTest(e) => e.fmt(f),
+            ThermocoupleError::Capacity(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<RangeError> for ThermocoupleError {
+    fn from(e: RangeError) -> Self {
+        ThermocoupleError::Range(e)
+    }
+}
+impl From<RangeError<Celsius>> for ThermocoupleError {
+    fn from(e: RangeError<Celsius>) -> Self {
+        ThermocoupleError::TemperatureRange(e)
+    }
+}
+impl From<AmbiguousVoltageError> for ThermocoupleError {
+    fn from(e: AmbiguousVoltageError) -> Self {
+        ThermocoupleError::Ambiguous(e)
+    }
+}
+impl From<ParseThermocoupleError> for ThermocoupleError {
+    fn from(e: ParseThermocoupleError) -> Self {
+        ThermocoupleError::Parse(e)
+    }
+}
+impl From<BelowAbsoluteZeroError> for ThermocoupleError {
+    fn from(e: BelowAbsoluteZeroError) -> Self {
+        ThermocoupleError::BelowAbsoluteZero(e)
+    }
+}
+impl From<SelfTestError> for ThermocoupleError {
+    fn from(e: SelfTestError) -> Self {
+        ThermocoupleError::SelfTest(e)
+    }
+}
+impl From<CapacityError> for ThermocoupleError {
+    fn from(e: CapacityError) -> Self {
+        ThermocoupleError::Capacity(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for RangeError<T> {}
+#[cfg(feature = "std")]
+impl std::error::Error for AmbiguousVoltageError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseThermocoupleError {}
+#[cfg(feature = "std")]
+impl std::error::Error for BelowAbsoluteZeroError {}
+#[cfg(feature = "std")]
+impl std::error::Error for SelfTestError {}
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ThermocoupleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThermocoupleError::Range(e) => Some(e),
+            ThermocoupleError::InverseUndefined(e) => Some(e),
+            ThermocoupleError::TemperatureRange(e) => Some(e),
+            ThermocoupleError::Ambiguous(e) => Some(e),
+            ThermocoupleError::Parse(e) => Some(e),
+            ThermocoupleError::BelowAbsoluteZero(e) => Some(e),
+            ThermocoupleError::SelfTest(e) => Some(e),
+            ThermocoupleError::Capacity(e) => Some(e),
+        }
+    }
+}
+
+/// Check that `value` lies within `range`, extended by `tolerance` at
+/// either end. Always succeeds when the `extrapolate` feature is
+/// enabled.
+pub(crate) fn check_range(
+    value: Millivolts,
+    range: (Millivolts, Millivolts),
+    tolerance: FP,
+) -> Result<(), RangeError> {
+    let (min, max) = range;
+
+    if cfg!(feature = "extrapolate")
+        || (value.0 >= min.0 - tolerance
+            && value.0 <= max.0 + tolerance)
+    {
+        Ok(())
+    } else {
+        Err(RangeError { value, min, max })
+    }
+}
+
+/// Check that `value` lies within the temperature `range`. Always
+/// succeeds when the `extrapolate` feature is enabled.
+pub(crate) fn check_temperature_range(
+    value: Celsius,
+    range: (Celsius, Celsius),
+) -> Result<(), RangeError<Celsius>> {
+    let (min, max) = range;
+
+    if cfg!(feature = "extrapolate") || (value >= min && value <= max) {
+        Ok(())
+    } else {
+        Err(RangeError { value, min, max })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fahrenheit, JType, ThermocoupleCore};
+
+    /// Each error converts with `?`
+    fn pipeline(name: &str) -> Result<Celsius, ThermocoupleError> {
+        let thermocouple = JType::new();
+        let _ = thermocouple.try_sense_voltage(Celsius(100.0))?;
+        let t: Celsius =
+            thermocouple.try_sense_temperature(Millivolts(1.1))?;
+        let _: crate::Thermocouple = name.parse()?;
+        crate::self_test()?;
+
+        Ok(Celsius::checked_from(t)?)
+    }
+
+    #[test]
+    fn unified() {
+        assert!(pipeline("J").is_ok());
+        assert_eq!(
+            pipeline("X"),
+            Err(ThermocoupleError::Parse(ParseThermocoupleError))
+        );
+
+        let error: ThermocoupleError =
+            Celsius::checked_from(Fahrenheit(-500.0))
+                .unwrap_err()
+                .into();
+        assert!(matches!(
+            error,
+            ThermocoupleError::BelowAbsoluteZero(_)
+        ));
+        assert_eq!(
+            format!("{}", error),
+            format!(
+                "{}",
+                Celsius::checked_from(Fahrenheit(-500.0)).unwrap_err()
+            )
+        );
+    }
+
+    #[test]
+    /// Test the overshoot is signed by the side of the range
+    fn overshoot() {
+        let (min, max) = (Millivolts(-8.0), Millivolts(69.5));
+        let error = |value| RangeError { value, min, max };
+
+        assert_eq!(
+            error(Millivolts(70.0)).overshoot(),
+            Millivolts(0.5)
+        );
+        assert_eq!(
+            error(Millivolts(-9.0)).overshoot(),
+            Millivolts(-1.0)
+        );
+        assert_eq!(error(Millivolts(1.0)).overshoot(), Millivolts(0.0));
+    }
+}
@@ -0,0 +1,79 @@
+//! Exponential function without libm.
+//!
+//! Used for the K-Type exponential term where libm is unavailable,
+//! either because the evaluation is in a `const` context or because the
+//! `no-libm` feature is enabled.
+
+use crate::FP;
+
+#[cfg(feature = "f32")]
+const LN_2: FP = core::f32::consts::LN_2;
+#[cfg(feature = "f64")]
+const LN_2: FP = core::f64::consts::LN_2;
+
+/// Below this, e^x underflows to zero
+#[cfg(feature = "f32")]
+const X_MIN: FP = -104.0;
+#[cfg(feature = "f64")]
+const X_MIN: FP = -746.0;
+/// Above this, e^x overflows to infinity
+#[cfg(feature = "f32")]
+const X_MAX: FP = 89.0;
+#[cfg(feature = "f64")]
+const X_MAX: FP = 710.0;
+
+/// Evaluate e^x in a `const fn`
+pub(crate) const fn exp(x: FP) -> FP {
+    if x < X_MIN {
+        return 0.0;
+    } else if x > X_MAX {
+        return FP::INFINITY;
+    }
+
+    // e^x = 2^k e^r, where |r| <= ln(2)/2
+    let mut k = match x < 0.0 {
+        true => (x / LN_2 - 0.5) as i32,
+        false => (x / LN_2 + 0.5) as i32,
+    };
+    let r = x - (k as FP) * LN_2;
+
+    // Taylor series for e^r
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut n = 1;
+    while n < 16 {
+        term *= r / (n as FP);
+        sum += term;
+        n += 1;
+    }
+
+    while k > 0 {
+        sum *= 2.0;
+        k -= 1;
+    }
+    while k < 0 {
+        sum *= 0.5;
+        k += 1;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exp;
+    use crate::tests::compare;
+    use crate::FP;
+
+    #[test]
+    fn test_exp() {
+        for i in -80..10 {
+            let x = i as FP;
+            let relative = exp(x) / x.exp();
+            compare(relative, 1.0, 1e-5);
+        }
+
+        assert_eq!(exp(FP::NEG_INFINITY), 0.0);
+        assert_eq!(exp(FP::INFINITY), FP::INFINITY);
+    }
+}
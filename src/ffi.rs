@@ -0,0 +1,83 @@
+//! Conversion functions with plain `f64` arguments and results.
+//!
+//! These wrap the typed API for use across a foreign function
+//! interface, for example with `wasm-bindgen`, where the unit newtypes
+//! cannot be passed directly. Temperatures are in Celsius and
+//! thermoelectric potentials are in millivolts.
+
+// FP may already be f64
+#![allow(trivial_numeric_casts)]
+
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
+use crate::KType;
+use crate::{
+    BType, Celsius, EType, JType, Millivolts, NType, RType, SType,
+    TType, ThermocoupleCore, FP,
+};
+
+macro_rules! ffi {
+    ($($(#[$attr:meta])* $Type:ident: $temperature:ident, $voltage:ident;)*) => {
+        $(
+            $(#[$attr])*
+            /// Returns the thermocouple temperature for a
+            /// thermoelectric potential of `millivolts`, with the
+            /// reference junction at `reference_celsius`.
+            pub fn $temperature(
+                millivolts: f64,
+                reference_celsius: f64,
+            ) -> f64 {
+                let temperature: Celsius = $Type::new()
+                    .with_reference_temperature(Celsius(
+                        reference_celsius as FP,
+                    ))
+                    .sense_temperature(Millivolts(millivolts as FP));
+
+                f64::from(temperature.0)
+            }
+            $(#[$attr])*
+            /// Returns the thermoelectric potential for a thermocouple
+            /// temperature of `celsius`, with the reference junction at
+            /// `reference_celsius`.
+            pub fn $voltage(celsius: f64, reference_celsius: f64) -> f64 {
+                let voltage = $Type::new()
+                    .with_reference_temperature(Celsius(
+                        reference_celsius as FP,
+                    ))
+                    .sense_voltage(Celsius(celsius as FP));
+
+                f64::from(voltage.0)
+            }
+        )*
+    };
+}
+
+ffi! {
+    BType: b_type_temperature, b_type_voltage;
+    EType: e_type_temperature, e_type_voltage;
+    JType: j_type_temperature, j_type_voltage;
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    KType: k_type_temperature, k_type_voltage;
+    NType: n_type_temperature, n_type_voltage;
+    RType: r_type_temperature, r_type_voltage;
+    SType: s_type_temperature, s_type_voltage;
+    TType: t_type_temperature, t_type_voltage;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::unnecessary_cast)] // FP may already be f64
+    /// Test the flat functions match the typed API
+    fn matches_typed() {
+        let thermocouple =
+            JType::new().with_reference_temperature(Celsius(20.0));
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(2.5));
+        let voltage = thermocouple.sense_voltage(Celsius(150.0));
+
+        assert_eq!(j_type_temperature(2.5, 20.0), temperature.0 as f64);
+        assert_eq!(j_type_voltage(150.0, 20.0), voltage.0 as f64);
+    }
+}
@@ -0,0 +1,164 @@
+//! Filters for thermocouple temperatures.
+//!
+//! The `sense_temperature` methods on thermocouples are pure. These
+//! filters hold state between readings, so take `&mut self`.
+
+use crate::{Millivolts, ThermocoupleCore, FP};
+use core::ops::{Add, Mul, Sub};
+
+/// Hysteresis, or deadband, filter. The output only changes when a
+/// sample moves more than `band` away from the last output, so that
+/// noise in the last digit of a display does not cause it to flicker.
+#[derive(Clone, Copy, Debug)]
+pub struct Hysteresis<W> {
+    band: W,
+    output: Option<W>,
+}
+
+impl<W> Hysteresis<W>
+where
+    W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+{
+    /// New filter with a deadband of `band` either side of the output
+    pub fn new(band: W) -> Self {
+        Hysteresis { band, output: None }
+    }
+    /// Filters a sample, returning the new output. The first sample is
+    /// always passed through.
+    pub fn update(&mut self, sample: W) -> W {
+        let output = match self.output {
+            Some(output)
+                if sample <= output + self.band
+                    && sample >= output - self.band =>
+            {
+                output
+            }
+            _ => sample,
+        };
+
+        self.output = Some(output);
+        output
+    }
+    /// Clears the last output, so that the next sample is passed
+    /// through
+    pub fn reset(&mut self) {
+        self.output = None;
+    }
+}
+
+/// First-order IIR filter, or exponential moving average. Each output
+/// is `alpha * sample + (1 - alpha) * output`, so a smaller `alpha`
+/// gives more smoothing.
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialFilter<W> {
+    alpha: FP,
+    state: Option<W>,
+}
+
+impl<W> ExponentialFilter<W>
+where
+    W: Copy + Add<Output = W> + Mul<FP, Output = W>,
+{
+    /// New filter with smoothing factor `alpha`, which should be
+    /// between 0 and 1
+    pub fn new(alpha: FP) -> Self {
+        debug_assert!((0.0..=1.0).contains(&alpha));
+
+        ExponentialFilter { alpha, state: None }
+    }
+    /// Filters a sample, returning the new output. The first sample
+    /// initialises the filter, and is passed through.
+    pub fn update(&mut self, sample: W) -> W {
+        let state = match self.state {
+            Some(state) => {
+                sample * self.alpha + state * (1.0 - self.alpha)
+            }
+            None => sample,
+        };
+
+        self.state = Some(state);
+        state
+    }
+    /// Returns the last output, if there has been a sample
+    pub fn state(&self) -> Option<W> {
+        self.state
+    }
+    /// Clears the filter state, so that the next sample initialises it
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// A thermocouple with a hysteresis filter on its sensed temperature
+#[derive(Clone, Copy, Debug)]
+pub struct WithHysteresis<T, W> {
+    thermocouple: T,
+    filter: Hysteresis<W>,
+}
+
+impl<T, W> WithHysteresis<T, W>
+where
+    T: ThermocoupleCore<W>,
+    W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+{
+    /// New filtered thermocouple with a deadband of `band`
+    pub fn new(thermocouple: T, band: W) -> Self {
+        WithHysteresis {
+            thermocouple,
+            filter: Hysteresis::new(band),
+        }
+    }
+    /// Returns the filtered thermocouple temperature for a given
+    /// thermoelectric potential.
+    pub fn sense_temperature(&mut self, voltage: Millivolts) -> W {
+        let sample = self.thermocouple.sense_temperature(voltage);
+
+        self.filter.update(sample)
+    }
+    /// Returns the unfiltered thermocouple
+    pub fn into_inner(self) -> T {
+        self.thermocouple
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExponentialFilter, Hysteresis};
+    use crate::tests::compare;
+    use crate::{Celsius, Fahrenheit, FP};
+
+    #[test]
+    fn hysteresis() {
+        let mut filter = Hysteresis::new(Celsius(0.5));
+
+        assert_eq!(filter.update(Celsius(20.0)), Celsius(20.0));
+        assert_eq!(filter.update(Celsius(20.3)), Celsius(20.0));
+        assert_eq!(filter.update(Celsius(19.6)), Celsius(20.0));
+        assert_eq!(filter.update(Celsius(20.6)), Celsius(20.6));
+        assert_eq!(filter.update(Celsius(20.4)), Celsius(20.6));
+
+        filter.reset();
+        assert_eq!(filter.update(Celsius(20.4)), Celsius(20.4));
+    }
+
+    #[test]
+    /// Test the response to a step converges at the expected rate
+    fn exponential_step() {
+        let mut filter = ExponentialFilter::new(0.5);
+
+        assert_eq!(filter.update(Celsius(0.0)), Celsius(0.0));
+        compare(filter.update(Celsius(10.0)).0, 5.0, 1e-6);
+        compare(filter.update(Celsius(10.0)).0, 7.5, 1e-6);
+        compare(filter.update(Celsius(10.0)).0, 8.75, 1e-6);
+
+        // Step response of a first-order filter is 1 - (1 - alpha)^n
+        let mut filter = ExponentialFilter::new(0.1);
+        let _ = filter.update(Fahrenheit(0.0));
+        for _ in 0..20 {
+            let _ = filter.update(Fahrenheit(100.0));
+        }
+        let decay: FP = 0.9;
+        let expected = 100.0 * (1.0 - decay.powi(20));
+        compare(filter.state().unwrap().0, expected, 1e-3);
+    }
+}
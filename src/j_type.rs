@@ -1,6 +1,9 @@
 //! J-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const J_TYPE_E_BELOW_760: [FP; 9] = [
     0.000000000000E+00,
     0.503811878150E-01,
@@ -12,6 +15,7 @@ const J_TYPE_E_BELOW_760: [FP; 9] = [
     -0.125383953360E-18,
     0.156317256970E-22,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const J_TYPE_E_ABOVE_760: [FP; 6] = [
     0.296456256810E+03,
     -0.149761277860E+01,
@@ -21,6 +25,7 @@ const J_TYPE_E_ABOVE_760: [FP; 6] = [
     -0.306913690560E-12,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const J_TYPE_T0: [FP; 9] = [
     0.0000000E+00,
     1.9528268E+01,
@@ -32,6 +37,7 @@ const J_TYPE_T0: [FP; 9] = [
     -2.3963370E-03,
     -8.3823321E-05,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const J_TYPE_T1: [FP; 9] = [
     0.000000E+00,
     1.978425E+01,
@@ -43,6 +49,7 @@ const J_TYPE_T1: [FP; 9] = [
     5.099890E-10,
     0.000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const J_TYPE_T2: [FP; 9] = [
     -3.11358187E+03,
     3.00543684E+02,
@@ -55,76 +62,267 @@ const J_TYPE_T2: [FP; 9] = [
     0.00000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const J_TYPE_E_BELOW_760: [FP; 6] = [
+    -0.1282457876E-01,
+    0.5050171151E-01,
+    0.3199699036E-04,
+    -0.9310090320E-07,
+    0.1154798516E-09,
+    -0.4569747461E-13,
+];
+#[cfg(feature = "reduced-order")]
+const J_TYPE_E_ABOVE_760: [FP; 3] = [
+    -0.1285354577E+02,
+    0.8159167521E-01,
+    -0.1079311703E-04,
+];
+#[cfg(feature = "reduced-order")]
+const J_TYPE_T0: [FP; 6] = [
+    0.3360236723E+00,
+    0.2134391843E+02,
+    0.1311527397E+01,
+    0.6315203210E+00,
+    0.1016693662E+00,
+    0.6456512245E-02,
+];
+#[cfg(feature = "reduced-order")]
+const J_TYPE_T1: [FP; 6] = [
+    -0.7514204093E-01,
+    0.1986751093E+02,
+    -0.2171519967E+00,
+    0.1145573883E-01,
+    -0.2617518574E-03,
+    0.1989349633E-05,
+];
+#[cfg(feature = "reduced-order")]
+const J_TYPE_T2: [FP; 6] = [
+    -0.3118311088E+04,
+    0.3007686866E+03,
+    -0.9948489717E+01,
+    0.1701585186E+00,
+    -0.1428112394E-02,
+    0.4726858169E-05,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &J_TYPE_E_BELOW_760,
+    &J_TYPE_E_ABOVE_760,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &J_TYPE_T0,
+    &J_TYPE_T1,
+    &J_TYPE_T2,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    J_TYPE_E_BELOW_760[0] == 0.0
+        && J_TYPE_T0[0] == 0.0
+        && J_TYPE_T1[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0], E_STEP_MAX));
+
+    let [v0, v1] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0, v1.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-210.0), Celsius(1200.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-8.095), Millivolts(69.553));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-8.095), Millivolts(69.553));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(760.0)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 2] =
+    [Millivolts(0.0), Millivolts(42.919)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(-100.0), Millivolts(-4.633)),
+    (Celsius(500.0), Millivolts(27.393)),
+    (Celsius(1000.0), Millivolts(57.953)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a J-Type thermocouple in the range -210ºC to
 /// 1200ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -210.0);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1200.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
 
-    let e = match t > 760.0 {
+    e_unchecked(t)
+}
+
+/// Evaluate E(T) for a J-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // -210ºC -> 760ºC
-            const C: [FP; 9] = J_TYPE_E_BELOW_760;
+            let c = J_TYPE_E_BELOW_760;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
         _ => {
             // 760ºC -> 1200ºC
-            const C: [FP; 6] = J_TYPE_E_ABOVE_760;
+            let c = J_TYPE_E_ABOVE_760;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a J-Type thermocouple in
+/// the range -210ºC to 1200ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// J-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(J_TYPE_E_BELOW_760, t),
+        _ => polyval_with_derivative(J_TYPE_E_ABOVE_760, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a J-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (-40.0..=750.0).contains(&t) => {
+            deviation(t, 1.5, 0.004)
+        }
+        ToleranceClass::Two if (-40.0..=750.0).contains(&t) => {
+            deviation(t, 2.5, 0.0075)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// Range of temperatures over which JX extension wire is defined by
+/// IEC 60584-3
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> =
+    Some((Celsius(-25.0), Celsius(200.0)));
+
+/// Evaluate the permissible deviation for JX extension wire of the given
+/// IEC 60584-3 tolerance class, within its temperature range. Returns
+/// `None` if the class is not defined.
+pub fn extension_tolerance(class: ToleranceClass) -> Option<Celsius> {
+    match class {
+        ToleranceClass::One => Some(Celsius(1.5)),
+        ToleranceClass::Two => Some(Celsius(2.5)),
+        _ => None,
+    }
+}
+
 /// Evaluate T for a J-Type thermocouple given E(T) in the range
 /// -8.095mV to 69.553mV, where T is in Celsius and E(T) is in millivolts.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -8.095 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 69.553 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match (e < 0.0, e < 42.919) {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a J-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a J-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0, b1] = INVERSE_BREAKPOINTS;
+    let c = match (e < b0.0, e < b1.0) {
         (true, _) => J_TYPE_T0,
         (false, true) => J_TYPE_T1,
         (false, false) => J_TYPE_T2,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -134,6 +332,10 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 0.8;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
         "../nist/type_j.tab.rs", -210, 1200, |t| t <= 1200
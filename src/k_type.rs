@@ -1,14 +1,19 @@
 //! K-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
+#[cfg(feature = "core-exp")]
+use core::sync::atomic::{AtomicPtr, Ordering};
 
-#[cfg(any(feature = "f32"))]
+#[cfg(all(feature = "f32", not(feature = "no-libm")))]
 #[allow(unused_imports)]
 use libm::F32Ext;
 
-#[cfg(any(feature = "f64"))]
+#[cfg(all(feature = "f64", not(feature = "no-libm")))]
 #[allow(unused_imports)]
 use libm::F64Ext;
 
+#[cfg(not(feature = "reduced-order"))]
 const K_TYPE_E_BELOW_0: [FP; 11] = [
     0.000000000000E+00,
     0.394501280250E-01,
@@ -22,6 +27,7 @@ const K_TYPE_E_BELOW_0: [FP; 11] = [
     -0.198892668780E-19,
     -0.163226974860E-22,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const K_TYPE_E_ABOVE_0: [FP; 10] = [
     -0.176004136860E-01,
     0.389212049750E-01,
@@ -34,7 +40,14 @@ const K_TYPE_E_ABOVE_0: [FP; 10] = [
     0.971511471520E-22,
     -0.121047212750E-25,
 ];
+/// Exponential term a0 * exp(a1 * (t - a2)²) above 0ºC
+const K_TYPE_E_ABOVE_0_EXP: [FP; 3] = [
+    0.118597600000E+00,
+    -0.118343200000E-03,
+    0.126968600000E+03,
+];
 
+#[cfg(not(feature = "reduced-order"))]
 const K_TYPE_T0: [FP; 10] = [
     0.0000000E+00,
     2.5173462E+01,
@@ -47,6 +60,7 @@ const K_TYPE_T0: [FP; 10] = [
     -5.1920577E-04,
     0.0000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const K_TYPE_T1: [FP; 10] = [
     0.000000E+00,
     2.508355E+01,
@@ -59,6 +73,7 @@ const K_TYPE_T1: [FP; 10] = [
     1.057734E-06,
     -1.052755E-08,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const K_TYPE_T2: [FP; 10] = [
     -1.318058E+02,
     4.830222E+01,
@@ -72,92 +87,367 @@ const K_TYPE_T2: [FP; 10] = [
     0.000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const K_TYPE_E_BELOW_0: [FP; 4] = [
+    -0.2228490410E-02,
+    0.3933659473E-01,
+    0.2733899874E-04,
+    -0.1103885009E-06,
+];
+#[cfg(feature = "reduced-order")]
+const K_TYPE_E_ABOVE_0: [FP; 5] = [
+    -0.3638274876E-02,
+    0.3898954686E-01,
+    0.7560654414E-05,
+    -0.6057250095E-08,
+    0.7909893960E-12,
+];
+#[cfg(feature = "reduced-order")]
+const K_TYPE_T0: [FP; 6] = [
+    0.2386063722E+00,
+    0.2678716790E+02,
+    0.1636980289E+01,
+    0.1213523936E+01,
+    0.2646290424E+00,
+    0.2388130064E-01,
+];
+#[cfg(feature = "reduced-order")]
+const K_TYPE_T1: [FP; 6] = [
+    0.6577203697E+00,
+    0.2425214198E+02,
+    -0.5534995365E-01,
+    0.2183885217E-01,
+    -0.1757395646E-02,
+    0.3995843486E-04,
+];
+#[cfg(feature = "reduced-order")]
+const K_TYPE_T2: [FP; 6] = [
+    -0.6784423411E+02,
+    0.3694510908E+02,
+    -0.8244962183E+00,
+    0.2364118319E-01,
+    -0.3205740890E-03,
+    0.1799436416E-05,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order.
+/// The exponential term above 0ºC is not included.
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &K_TYPE_E_BELOW_0,
+    &K_TYPE_E_ABOVE_0,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &K_TYPE_T0,
+    &K_TYPE_T1,
+    &K_TYPE_T2,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    K_TYPE_E_BELOW_0[0] == 0.0
+        && K_TYPE_T0[0] == 0.0
+        && K_TYPE_T1[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint. The
+// exponential term closes the step in E(T) at 0ºC.
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let [a0, a1, a2] = K_TYPE_E_ABOVE_0_EXP;
+    let es = a0 * crate::exp::exp(a1 * (b0.0 - a2) * (b0.0 - a2));
+    let step = polyval(K_TYPE_E_ABOVE_0, b0.0) + es
+        - polyval(K_TYPE_E_BELOW_0, b0.0);
+    assert!(step <= E_STEP_MAX && step >= -E_STEP_MAX);
+
+    let [v0, v1] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0, v1.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-270.0), Celsius(1372.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-6.458), Millivolts(54.886));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-5.891), Millivolts(54.886));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(0.0)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 2] =
+    [Millivolts(0.0), Millivolts(20.644)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts =
+    e_const(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(-100.0), Millivolts(-3.554)),
+    (Celsius(300.0), Millivolts(12.209)),
+    (Celsius(1000.0), Millivolts(41.276)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e_const(Celsius(t)).0);
+
 /// Evaluate E(T) for a K-type thermocouple in the range -270ºC to
 /// 1372ºC, where T is in Celsius and E(T) is in millivolts.
 pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -270.0);
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1372.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
+
+    e_unchecked(t)
+}
 
-    let e = match t > 0.0 {
+/// Evaluate E(T) for a K-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // -270ºC -> 0ºC
-            const C: [FP; 11] = K_TYPE_E_BELOW_0;
+            let c = K_TYPE_E_BELOW_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
-                + C[10] * t * t * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
         _ => {
             // 0ºC -> 1372ºC
-            const C: [FP; 10] = K_TYPE_E_ABOVE_0;
-            let a0 = 0.118597600000E+00;
-            let a1 = -0.118343200000E-03;
-            let a2 = 0.126968600000E+03;
+            let c = K_TYPE_E_ABOVE_0;
 
             // Power Series
-            let ps = C[0]
-                + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t;
+            let ps = polyval(c, t);
 
-            // Exponential
-            let es = a0 * (a1 * (t - a2) * (t - a2)).exp();
+            ps + exp_correction(t)
+        }
+    };
 
-            ps + es
+    Millivolts(e)
+}
+
+/// Evaluate E(T) for a K-Type thermocouple in a `const` context,
+/// without checking that T is within the range of the reference
+/// function. As `e` is not a `const fn`, the exponential term is
+/// evaluated with `exp::exp` instead.
+pub(crate) const fn e_const(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
+        false => polyval(K_TYPE_E_BELOW_0, t),
+        _ => {
+            let [a0, a1, a2] = K_TYPE_E_ABOVE_0_EXP;
+            let es = a0 * crate::exp::exp(a1 * (t - a2) * (t - a2));
+
+            polyval(K_TYPE_E_ABOVE_0, t) + es
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the exponential term a0 * exp(a1 * (t - a2)²) of E(T)
+/// above 0ºC, where T is in Celsius and the result is in millivolts.
+fn exp_correction(t: FP) -> FP {
+    let [a0, a1, a2] = K_TYPE_E_ABOVE_0_EXP;
+
+    a0 * exp(a1 * (t - a2) * (t - a2))
+}
+
+/// Evaluate e^x with libm
+#[cfg(not(feature = "no-libm"))]
+fn default_exp(x: FP) -> FP {
+    x.exp()
+}
+
+/// Evaluate e^x without libm
+#[cfg(feature = "no-libm")]
+fn default_exp(x: FP) -> FP {
+    crate::exp::exp(x)
+}
+
+/// Evaluate e^x
+#[cfg(not(feature = "core-exp"))]
+fn exp(x: FP) -> FP {
+    default_exp(x)
+}
+
+/// Function set by `set_exp`, or null if there is none
+#[cfg(feature = "core-exp")]
+static EXP: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Sets the function used to evaluate e^x in the exponential term of
+/// E(T), for example to route it to a platform intrinsic. Until this
+/// is called, the term is evaluated with libm, or with the crate's own
+/// `exp` if the `no-libm` feature is enabled.
+#[cfg(feature = "core-exp")]
+pub fn set_exp(exp: fn(FP) -> FP) {
+    EXP.store(exp as *mut (), Ordering::Relaxed);
+}
+
+/// Evaluate e^x with the function set by `set_exp`, if there is one
+#[cfg(feature = "core-exp")]
+fn exp(x: FP) -> FP {
+    let exp = EXP.load(Ordering::Relaxed);
+    if exp.is_null() {
+        return default_exp(x);
+    }
+
+    // SAFETY: `EXP` is only ever stored from a `fn(FP) -> FP`, in
+    // `set_exp`, and function pointers are the size of a data pointer
+    #[allow(unsafe_code)]
+    let exp = unsafe { core::mem::transmute::<*mut (), fn(FP) -> FP>(exp) };
+    exp(x)
+}
+
+/// Evaluate the Seebeck coefficient dE/dT for a K-type thermocouple in
+/// the range -270ºC to 1372ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// K-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(K_TYPE_E_BELOW_0, t),
+        _ => {
+            let [_, a1, a2] = K_TYPE_E_ABOVE_0_EXP;
+
+            // Power Series
+            let (ps, dps) = polyval_with_derivative(K_TYPE_E_ABOVE_0, t);
+
+            // Exponential
+            let es = exp_correction(t);
+
+            (ps + es, dps + es * 2.0 * a1 * (t - a2))
+        }
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a K-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (-40.0..=1000.0).contains(&t) => {
+            deviation(t, 1.5, 0.004)
+        }
+        ToleranceClass::Two if (-40.0..=1200.0).contains(&t) => {
+            deviation(t, 2.5, 0.0075)
+        }
+        ToleranceClass::Three if (-200.0..=40.0).contains(&t) => {
+            deviation(t, 2.5, 0.015)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
 /// Evaluate T for a K-type thermocouple given E(T) in the range
 /// -5.891mV to 54.886mV, where T is in Celsius and E(T) is in millivolts.
+///
+/// NIST does not define an inverse function below -200ºC, so E(T) from
+/// -270ºC to -200ºC is out of range even though E(T) is defined there.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -5.891 - TOL);
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 54.886 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
+
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a K-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Range of temperatures over which KX extension wire is defined by
+/// IEC 60584-3
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> =
+    Some((Celsius(-25.0), Celsius(200.0)));
 
-    let c = match (e < 0.0, e < 20.644) {
+/// Evaluate the permissible deviation for KX extension wire of the given
+/// IEC 60584-3 tolerance class, within its temperature range. Returns
+/// `None` if the class is not defined.
+pub fn extension_tolerance(class: ToleranceClass) -> Option<Celsius> {
+    match class {
+        ToleranceClass::One => Some(Celsius(1.5)),
+        ToleranceClass::Two => Some(Celsius(2.5)),
+        _ => None,
+    }
+}
+
+/// Evaluate T for a K-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0, b1] = INVERSE_BREAKPOINTS;
+    let c = match (e < b0.0, e < b1.0) {
         (true, _) => K_TYPE_T0,
         (false, true) => K_TYPE_T1,
         (false, false) => K_TYPE_T2,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e
-        + c[9] * e * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -167,8 +457,32 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 1.0;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
-        "../nist/type_k.tab.rs", -270, 1372, |t| t >= -200 && t < 1372
+        "../nist/type_k.tab.rs", -270, 1372, |t| (-200..1372).contains(&t)
+    }
+
+    #[cfg(feature = "core-exp")]
+    #[test]
+    /// Test the exponential term is evaluated with the function set by
+    /// `set_exp`
+    fn set_exp() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn counting_exp(x: FP) -> FP {
+            let _ = CALLS.fetch_add(1, Ordering::Relaxed);
+            super::default_exp(x)
+        }
+
+        super::set_exp(counting_exp);
+        let e = super::e(Celsius(500.0));
+
+        assert!(CALLS.load(Ordering::Relaxed) > 0);
+        compare(e.0, 20.644, 0.005);
     }
 }
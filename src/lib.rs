@@ -6,6 +6,9 @@
 //! * Nickel-alloy thermocouples: Type E, J, K, N, T
 //! * Platinum/rhodium-alloy thermocouples: Type B, R, S
 //!
+//! Platinum resistance thermometers (Pt100 and Pt1000) are also
+//! supported.
+//!
 //! This library includes _newtype_ types, or wrapper types, to help with using
 //! the proper units when doing calculations. I investigated using a dimensional
 //! analysis crate such as uom or dimensioned instead of making my own newtype
@@ -32,9 +35,15 @@
 //!
 //! The temperature of a thermocouple can be calcuated from a voltage reading.
 //!
-#![cfg_attr(any(feature = "k-type"), doc = " ```rust")]
-#![cfg_attr(not(any(feature = "k-type")), doc = " ```rust,ignore")]
-//! use thermocouple::{prelude::*, KType};
+#![cfg_attr(
+    any(feature = "k-type", feature = "no-libm"),
+    doc = " ```rust"
+)]
+#![cfg_attr(
+    not(any(feature = "k-type", feature = "no-libm")),
+    doc = " ```rust,ignore"
+)]
+//! use thermocouple::prelude::*;
 //!
 //! // Assuming reference junction at 25ºC
 //! let temperature: Celsius = KType::new().sense_temperature(Millivolts(1.1));
@@ -42,9 +51,15 @@
 //!
 //! The reference junction temperature can be set explicitly.
 //!
-#![cfg_attr(any(feature = "k-type"), doc = " ```rust")]
-#![cfg_attr(not(any(feature = "k-type")), doc = " ```rust,ignore")]
-//! use thermocouple::{prelude::*, KType};
+#![cfg_attr(
+    any(feature = "k-type", feature = "no-libm"),
+    doc = " ```rust"
+)]
+#![cfg_attr(
+    not(any(feature = "k-type", feature = "no-libm")),
+    doc = " ```rust,ignore"
+)]
+//! use thermocouple::prelude::*;
 //!
 //! // Reference junction at 0ºC
 //! let thermocouple =
@@ -54,6 +69,71 @@
 //!     thermocouple.sense_temperature(Millivolts(2.0));
 //! ```
 //!
+//! If the voltage has already been cold-junction compensated, for
+//! example by the analog front-end, the inverse function can be
+//! applied directly.
+//!
+#![cfg_attr(
+    any(feature = "k-type", feature = "no-libm"),
+    doc = " ```rust"
+)]
+#![cfg_attr(
+    not(any(feature = "k-type", feature = "no-libm")),
+    doc = " ```rust,ignore"
+)]
+//! use thermocouple::prelude::*;
+//!
+//! let temperature: Celsius =
+//!     KType::direct().sense_temperature(Millivolts(2.0));
+//! ```
+//!
+//! ## Range of the inverse functions
+//!
+//! For Types E, K, N and T the reference function E(T) is defined down
+//! to -270ºC, but NIST only defines the inverse function down to
+//! -200ºC. For a thermoelectric potential between the two,
+//! `try_sense_temperature` returns
+//! [`ThermocoupleError::InverseUndefined`] rather than
+//! [`ThermocoupleError::Range`]. Its `min` is the lower bound of
+//! [`INVERSE_VOLTAGE_RANGE`](ThermocoupleRange::INVERSE_VOLTAGE_RANGE)
+//! rather than [`VOLTAGE_RANGE`](ThermocoupleRange::VOLTAGE_RANGE).
+//! With the `extrapolate` feature the lowest inverse polynomial is used
+//! instead, which is inaccurate below -200ºC.
+//!
+//! ## Reduced-order polynomials
+//!
+//! Where code size matters more than accuracy, the `reduced-order`
+//! feature replaces the reference functions with lower-degree fits.
+//! Over the range of each inverse function, the worst-case error
+//! against the [NIST ITS-90 Thermocouple Database][ITS-90] is:
+//!
+//! | Type | Worst-case error |
+//! |------|------------------|
+//! | B    | ±0.7ºC           |
+//! | E    | ±1.0ºC           |
+//! | J    | ±0.8ºC           |
+//! | K    | ±1.0ºC           |
+//! | N    | ±0.6ºC           |
+//! | R    | ±1.0ºC           |
+//! | S    | ±1.0ºC           |
+//! | T    | ±0.6ºC           |
+//!
+//! ## Without libm
+//!
+//! The `k-type` feature depends on [libm](https://docs.rs/libm) for
+//! the exponential term of the Type K reference function. The `no-libm`
+//! feature provides the Type K without libm, evaluating the term with
+//! the crate's own `exp` instead, which is accurate to within the NIST
+//! tables. It replaces `k-type`, which is a default feature, so the
+//! opt-out needs default features disabled:
+//!
+//! ```toml
+//! thermocouple = { version = "0.1", default-features = false, features = ["f64", "no-libm"] }
+//! ```
+//!
+//! libm is then not a dependency at all, as
+//! `cargo tree --no-default-features --features f64,no-libm` shows.
+//!
 //! ## Tests
 //!
 //! The tests check against every value provided in the [NIST ITS-90
@@ -69,7 +149,7 @@
 
 #![no_std]
 // rustc lints.
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![warn(
     bare_trait_objects,
     missing_copy_implementations,
@@ -83,14 +163,17 @@
     unused_results
 )]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(test, macro_use)]
 extern crate std;
 
-#[cfg(any(feature = "f32"))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "f32")]
 #[doc = "Underlying storage type: `f32`"]
 pub type FP = f32;
-#[cfg(any(feature = "f64"))]
+#[cfg(feature = "f64")]
 #[doc = "Underlying storage type: `f64`"]
 pub type FP = f64;
 #[cfg(not(any(feature = "f32", feature = "f64")))]
@@ -106,40 +189,348 @@ compile_error!(
 
 #[macro_use]
 mod test_utils;
+mod error;
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
+mod exp;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod filter;
+#[cfg(feature = "lut")]
+#[macro_use]
+mod lut;
+mod max31855;
+mod newton;
+mod polyval;
+mod reference_cache;
+mod rounding;
+mod rtd;
+mod runtime;
+mod scale;
+mod self_test;
+mod stats;
+mod tolerance;
 mod units;
+pub use error::{
+    AmbiguousVoltageError, BelowAbsoluteZeroError, CapacityError,
+    ParseThermocoupleError, RangeError, SelfTestError,
+    ThermocoupleError,
+};
+pub use filter::{ExponentialFilter, Hysteresis, WithHysteresis};
+pub use max31855::delinearize_max31855;
+pub use reference_cache::ReferenceCache;
+pub use rounding::RoundingMode;
+pub use rtd::{Pt100, Pt1000};
+#[cfg(feature = "std")]
+pub use runtime::dump_table;
+pub use runtime::{
+    recommend_type, sense_all_types, Thermocouple, ThermocoupleType,
+};
+pub use scale::TemperatureScale;
+pub use self_test::self_test;
+pub use stats::{max, mean, min, TemperatureStats};
+pub use tolerance::ToleranceClass;
 pub use units::{
-    Celsius, FPExt, Fahrenheit, Kelvin, Millivolts, Rankine, Reaumur,
+    convert, Celsius, Delisle, FPExt, Fahrenheit, Kelvin, Microvolts,
+    Millivolts, Newton, Ohms, Rankine, Reaumur, Romer, Temperature,
 };
 
+#[cfg(feature = "use_embedded_hal")]
+use embedded_hal::adc::{Channel, OneShot};
+
 /// Trait for thermocouple functionality
 pub trait ThermocoupleCore<W> {
     /// Returns the thermocouple temperature for a given
     /// thermoelectric potential.
     fn sense_temperature(&self, voltage: Millivolts) -> W;
+    /// Returns the thermocouple temperature for a given
+    /// thermoelectric potential, or an error if the potential is
+    /// outside the range of the inverse function.
+    ///
+    /// The default implementation never returns an error, so
+    /// implementations with a limited range should override it.
+    fn try_sense_temperature(
+        &self,
+        voltage: Millivolts,
+    ) -> Result<W, ThermocoupleError> {
+        Ok(self.sense_temperature(voltage))
+    }
     /// Return the thermoelectric potential for a given thermocouple
     /// temperature.
     fn sense_voltage(&self, temperature: W) -> Millivolts;
+    /// Return the thermoelectric potential for a given thermocouple
+    /// temperature, or an error if the temperature is outside the
+    /// range of the reference function.
+    ///
+    /// The default implementation never returns an error, so
+    /// implementations with a limited range should override it.
+    fn try_sense_voltage(
+        &self,
+        temperature: W,
+    ) -> Result<Millivolts, RangeError<Celsius>> {
+        Ok(self.sense_voltage(temperature))
+    }
+    /// Fills `out` with the thermocouple temperatures for each of the
+    /// thermoelectric potentials in `voltages`. The two slices should
+    /// be the same length.
+    fn sense_temperature_slice(
+        &self,
+        voltages: &[Millivolts],
+        out: &mut [W],
+    ) {
+        debug_assert_eq!(voltages.len(), out.len());
+
+        for (voltage, temperature) in
+            voltages.iter().zip(out.iter_mut())
+        {
+            *temperature = self.sense_temperature(*voltage);
+        }
+    }
+    /// Returns the thermocouple temperatures for each of the
+    /// thermoelectric potentials in `voltages`.
+    #[cfg(feature = "alloc")]
+    fn sense_temperature_vec(
+        &self,
+        voltages: &[Millivolts],
+    ) -> alloc::vec::Vec<W> {
+        voltages
+            .iter()
+            .map(|voltage| self.sense_temperature(*voltage))
+            .collect()
+    }
+    /// Returns the thermocouple temperatures for each of the
+    /// thermoelectric potentials in `voltages`, without allocating.
+    /// Returns an error, without converting any potentials, if there
+    /// are more than `N`.
+    #[cfg(feature = "use_heapless")]
+    fn sense_temperature_heapless<const N: usize>(
+        &self,
+        voltages: &[Millivolts],
+    ) -> Result<heapless::Vec<W, N>, CapacityError> {
+        if voltages.len() > N {
+            return Err(CapacityError {
+                len: voltages.len(),
+                capacity: N,
+            });
+        }
+
+        Ok(voltages
+            .iter()
+            .map(|voltage| self.sense_temperature(*voltage))
+            .collect())
+    }
+    /// Reads the thermoelectric potential from an ADC channel, and
+    /// returns the thermocouple temperature. `adc_to_mv` converts the
+    /// raw ADC reading to millivolts, including the ADC reference
+    /// voltage and any amplifier gain. Blocks until the conversion is
+    /// complete.
+    #[cfg(feature = "use_embedded_hal")]
+    fn read_temperature<ADC, Word, PIN, E>(
+        &self,
+        adc: &mut impl OneShot<ADC, Word, PIN, Error = E>,
+        pin: &mut PIN,
+        adc_to_mv: impl Fn(Word) -> Millivolts,
+    ) -> Result<W, E>
+    where
+        PIN: Channel<ADC>,
+    {
+        let raw = nb::block!(adc.read(pin))?;
+
+        Ok(self.sense_temperature(adc_to_mv(raw)))
+    }
+}
+
+/// Trait for the range over which a thermocouple type is defined
+pub trait ThermocoupleRange {
+    /// Range of temperatures over which the thermocouple is defined
+    const TEMPERATURE_RANGE: (Celsius, Celsius);
+    /// Range of thermoelectric potential over the full temperature
+    /// range, for a reference junction at 0ºC
+    const VOLTAGE_RANGE: (Millivolts, Millivolts);
+    /// Range of thermoelectric potential over which the inverse
+    /// function is defined, for a reference junction at 0ºC. This may
+    /// be narrower than `VOLTAGE_RANGE`.
+    const INVERSE_VOLTAGE_RANGE: (Millivolts, Millivolts);
+    /// Temperatures at which the reference function switches between
+    /// coefficient sets, in increasing order
+    const TEMPERATURE_BREAKPOINTS: &'static [Celsius];
+    /// Values of thermoelectric potential at which the inverse
+    /// function switches between coefficient sets, in increasing
+    /// order
+    const INVERSE_BREAKPOINTS: &'static [Millivolts];
+    /// Temperature at which the thermoelectric potential is zero, for
+    /// a reference junction at 0ºC
+    const ZERO_VOLTAGE_TEMPERATURE: Celsius;
+}
+
+/// A thermocouple temperature, along with the intermediate potentials
+/// used to find it
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Reading<W> {
+    /// Thermocouple temperature
+    pub temperature: W,
+    /// E(T) at the reference junction
+    pub reference_potential: Millivolts,
+    /// Measured potential plus the reference junction potential. This
+    /// is the potential the inverse function is applied to.
+    pub total_potential: Millivolts,
+}
+
+/// Handling of temperatures and potentials outside the range over which
+/// the reference functions are defined
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum RangeMode {
+    /// Panic, unless the `extrapolate` feature is enabled. The `try_`
+    /// methods return an error instead.
+    #[default]
+    Panic,
+    /// Extrapolate from the nearest polynomial. The result may be
+    /// inaccurate.
+    Extrapolate,
+    /// Clamp to the end of the range
+    Clamp,
 }
 
 macro_rules! thermocouple {
-    ($($Type:ident, $mod:ident: $doc:expr => $($unit:ty),+;)*) => {
+    ($($Type:ident, $mod:ident, $kind:ident: $doc:expr => $($unit:ty),+;)*) => {
         $(
-            mod $mod;
+            pub mod $mod;
 
             #[doc=$doc]
+            #[cfg_attr(
+                feature = "use_serde",
+                derive(serde::Serialize, serde::Deserialize)
+            )]
+            #[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
             #[derive(Clone, Copy, Debug)]
             pub struct $Type {
                 /// E(T) at the reference junction
                 reference_potential: Millivolts,
+                /// Reference junction temperature, unless the
+                /// potential was set directly
+                reference_temperature: Option<Celsius>,
+                /// Use the lookup table rather than the reference
+                /// functions
+                #[cfg(feature = "lut")]
+                interpolate: bool,
+                /// Calibration offset added to the temperature
+                offset: Celsius,
+                /// Calibration gain applied to the temperature
+                gain: FP,
+                /// Number of junction pairs in series
+                junctions: u16,
+                /// Offset in the measured potential, for example from
+                /// lead resistance and amplifier bias current
+                lead_offset: Millivolts,
+                /// Rated operating range of this particular probe
+                operating_range: Option<(Celsius, Celsius)>,
+                /// Connected with extension wire
+                extension_wire: bool,
+                /// The reference junction lies outside the range of the
+                /// extension wire
+                beyond_extension_range: bool,
+                /// Handling of temperatures and potentials outside the
+                /// range of the reference functions
+                range_mode: RangeMode,
+                /// Temperature scale for sensed temperatures
+                scale: TemperatureScale,
             }
             impl $Type {
+                /// Descriptive name of this type, for logging and
+                /// display
+                pub const NAME: &'static str = $doc;
+                /// Letter designation of this type
+                pub const LETTER: char =
+                    ThermocoupleType::$kind.letter();
+                /// Reference junction temperature assumed by `new`
+                pub const DEFAULT_REFERENCE: Celsius = Celsius(25.0);
+
                 /// New thermocouple instance. The reference junction is
-                /// assumed to be at 25ºC / 298.15K.
-                pub fn new() -> $Type {
+                /// assumed to be at [`DEFAULT_REFERENCE`], 25ºC /
+                /// 298.15K.
+                ///
+                /// [`DEFAULT_REFERENCE`]: Self::DEFAULT_REFERENCE
+                pub const fn new() -> $Type {
                     $Type {
-                        reference_potential: $mod::e((25.0).celsius()),
+                        reference_potential: $mod::DEFAULT_REFERENCE_POTENTIAL,
+                        reference_temperature: Some(Self::DEFAULT_REFERENCE),
+                        #[cfg(feature = "lut")]
+                        interpolate: false,
+                        offset: Celsius(0.0),
+                        gain: 1.0,
+                        junctions: 1,
+                        lead_offset: Millivolts(0.0),
+                        operating_range: None,
+                        extension_wire: false,
+                        beyond_extension_range: false,
+                        range_mode: RangeMode::Panic,
+                        scale: TemperatureScale::Its90,
+                    }
+                }
+                /// Returns the type of this thermocouple, for example
+                /// as a key for per-type data
+                pub const fn kind(&self) -> ThermocoupleType {
+                    ThermocoupleType::$kind
+                }
+                /// Returns the reference junction temperature, as set
+                /// by `new`, `at_reference` or a `with_reference_`
+                /// method.
+                ///
+                /// After `with_reference_potential` the temperature is
+                /// instead found by applying the inverse function to
+                /// the potential. The inverse function is not range
+                /// checked, so the result may then be an inaccurate
+                /// extrapolation. In particular, the Type B inverse
+                /// function is only defined above 250ºC, so the result
+                /// is meaningless for a Type B reference junction near
+                /// room temperature.
+                pub fn reference_temperature(&self) -> Celsius {
+                    match self.reference_temperature {
+                        Some(reference_temperature) => reference_temperature,
+                        None => $mod::t_unchecked(self.reference_potential),
                     }
                 }
+                /// Returns E(T), with the reference junction at 0ºC,
+                /// every `step` from the bottom to the top of the
+                /// temperature range. The final step may be shorter, so
+                /// that the curve ends exactly at the top of the range.
+                pub fn curve(
+                    step: Celsius,
+                ) -> impl Iterator<Item = (Celsius, Millivolts)> {
+                    assert!(step > Celsius(0.0));
+
+                    let (min, max) = $mod::TEMPERATURE_RANGE;
+                    // Saturates for a tiny step, rather than overflowing
+                    let steps = ((max.0 - min.0) / step.0) as usize;
+
+                    (0..=steps)
+                        .map(move |i| Celsius(min.0 + step.0 * (i as FP)))
+                        .take_while(move |t| *t < max)
+                        .chain(core::iter::once(max))
+                        .map(|t| (t, $mod::e(t)))
+                }
+                /// New thermocouple instance for voltages that are
+                /// already cold-junction compensated. The inverse
+                /// function is applied to the voltage unmodified.
+                pub const fn direct() -> $Type {
+                    let mut thermocouple = $Type::new();
+                    thermocouple.reference_potential = Millivolts(0.0);
+                    thermocouple.reference_temperature = Some(Celsius(0.0));
+                    thermocouple
+                }
+                /// New thermocouple instance with the reference
+                /// junction at `reference_temperature`.
+                pub fn at_reference<T>(reference_temperature: T) -> $Type
+                where
+                    T: Into<Celsius>,
+                {
+                    $Type::new()
+                        .with_reference_temperature(reference_temperature)
+                }
                 /// Sets the reference junction temperature used.
                 pub fn with_reference_temperature<T>(
                     self,
@@ -147,9 +538,717 @@ macro_rules! thermocouple {
                 ) -> Self where
                     T: Into<Celsius>,
                 {
-                    $Type {
-                        reference_potential: $mod::e(reference_temperature.into()),
+                    let reference_temperature = reference_temperature.into();
+
+                    let mut thermocouple = self;
+                    thermocouple.reference_potential =
+                        $Type::cold_junction_potential(reference_temperature);
+                    thermocouple.reference_temperature =
+                        Some(reference_temperature);
+                    thermocouple.with_extension_check()
+                }
+                /// Sets the reference junction temperature from a
+                /// reading of `cold_junction_voltage` by a second
+                /// thermocouple, `cold_junction`, which may be of a
+                /// different type.
+                pub fn with_reference_from<C>(
+                    self,
+                    cold_junction: &C,
+                    cold_junction_voltage: Millivolts,
+                ) -> Self
+                where
+                    C: ThermocoupleCore<Celsius>,
+                {
+                    let reference: Celsius =
+                        cold_junction.sense_temperature(cold_junction_voltage);
+
+                    self.with_reference_temperature(reference)
+                }
+                /// Returns this thermocouple with the reference
+                /// junction at `reference_temperature`, where later
+                /// updates to the reference junction temperature only
+                /// evaluate E(T) again if they differ by more than
+                /// `threshold`.
+                pub fn with_reference_cache<T>(
+                    self,
+                    reference_temperature: T,
+                    threshold: Celsius,
+                ) -> ReferenceCache<$Type>
+                where
+                    T: Into<Celsius>,
+                {
+                    let reference = reference_temperature.into();
+
+                    ReferenceCache {
+                        thermocouple: self
+                            .with_reference_temperature(reference),
+                        last_reference: reference,
+                        threshold,
+                    }
+                }
+                /// Sets the thermoelectric potential at the reference
+                /// junction directly, for example from a calibrated
+                /// source.
+                pub fn with_reference_potential(
+                    self,
+                    reference_potential: Millivolts,
+                ) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.reference_potential = reference_potential;
+                    thermocouple.reference_temperature = None;
+                    thermocouple.with_extension_check()
+                }
+                /// Sets the reference junction temperature used, in
+                /// Kelvin.
+                pub fn with_reference_kelvin(self, reference_temperature: Kelvin) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Fahrenheit.
+                pub fn with_reference_fahrenheit(self, reference_temperature: Fahrenheit) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Rankine.
+                pub fn with_reference_rankine(self, reference_temperature: Rankine) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Reaumur.
+                pub fn with_reference_reaumur(self, reference_temperature: Reaumur) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Newton.
+                pub fn with_reference_newton(self, reference_temperature: Newton) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Delisle.
+                pub fn with_reference_delisle(self, reference_temperature: Delisle) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Sets the reference junction temperature used, in
+                /// Romer.
+                pub fn with_reference_romer(self, reference_temperature: Romer) -> Self {
+                    self.with_reference_temperature(reference_temperature)
+                }
+                /// Returns the thermoelectric potential of the
+                /// reference (cold) junction at a given temperature.
+                pub fn cold_junction_potential<T>(
+                    reference_temperature: T,
+                ) -> Millivolts
+                where
+                    T: Into<Celsius>,
+                {
+                    $mod::e(reference_temperature.into())
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, with the reference
+                /// junction at `reference_temperature` rather than the
+                /// temperature this instance was constructed with.
+                /// Useful when the reference junction temperature is
+                /// measured as often as the thermoelectric potential.
+                #[doc(alias = "sense_temperature_ref")]
+                pub fn sense_temperature_with_reference<W, T>(
+                    &self,
+                    voltage: Millivolts,
+                    reference_temperature: T,
+                ) -> W
+                where
+                    Celsius: Into<W>,
+                    T: Into<Celsius>,
+                {
+                    let reference_potential =
+                        $Type::cold_junction_potential(reference_temperature);
+                    let t = self.t(
+                        self.junction_potential(voltage) + reference_potential,
+                    );
+
+                    self.calibrate(t).into()
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, where the reference
+                /// junction temperature is itself measured by
+                /// `cold_junction_voltage` from a second thermocouple
+                /// of the same type referenced to 0ºC. Both potentials
+                /// are handled according to the range mode.
+                pub fn sense_temperature_from_raw<W>(
+                    &self,
+                    measured: Millivolts,
+                    cold_junction_voltage: Millivolts,
+                ) -> W
+                where
+                    Celsius: Into<W>,
+                {
+                    let reference_potential = self.e_reference(
+                        self.t_reference(cold_junction_voltage),
+                    );
+                    let t = self.t(
+                        self.junction_potential(measured) + reference_potential,
+                    );
+
+                    self.calibrate(t).into()
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, where the reference
+                /// junction temperature is itself measured by
+                /// `cold_junction_voltage`, or an error if either
+                /// potential is out of range.
+                pub fn try_sense_temperature_from_raw<W>(
+                    &self,
+                    measured: Millivolts,
+                    cold_junction_voltage: Millivolts,
+                ) -> Result<W, ThermocoupleError>
+                where
+                    Celsius: Into<W>,
+                {
+                    self.check_inverse_range(cold_junction_voltage)?;
+                    let reference_potential = self.e_reference(
+                        self.t_reference(cold_junction_voltage),
+                    );
+
+                    let temperature: Celsius = self
+                        .with_reference_potential(reference_potential)
+                        .try_sense_temperature(measured)?;
+                    Ok(temperature.into())
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, refined by Newton-Raphson
+                /// iteration on the reference function. This removes
+                /// the error of the inverse polynomials, at the cost of
+                /// evaluating the reference function twice more.
+                pub fn sense_temperature_refined<W>(
+                    &self,
+                    voltage: Millivolts,
+                ) -> W
+                where
+                    Celsius: Into<W>,
+                {
+                    let e = self.junction_potential(voltage)
+                        + self.reference_potential;
+
+                    let t = newton::refine(
+                        e,
+                        self.t(e),
+                        $mod::e_and_seebeck_unchecked,
+                    );
+
+                    self.calibrate(t).into()
+                }
+                /// Sets a linear calibration for this particular
+                /// thermocouple. Sensed temperatures are corrected as
+                /// `gain * t + offset`, and the correction is inverted
+                /// when sensing voltages.
+                pub fn with_calibration(
+                    self,
+                    offset: Celsius,
+                    gain: FP,
+                ) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.offset = offset;
+                    thermocouple.gain = gain;
+                    thermocouple
+                }
+                /// Sets the number of identical junction pairs wired in
+                /// series, as in a thermopile. The measured potential
+                /// is divided between the junction pairs before
+                /// applying the inverse function.
+                ///
+                /// Panics if `n` is zero.
+                pub fn with_junction_count(self, n: u16) -> Self {
+                    assert!(n > 0, "junction count must be non-zero");
+
+                    let mut thermocouple = self;
+                    thermocouple.junctions = n;
+                    thermocouple
+                }
+                /// Sets a fixed offset in the measured potential, for
+                /// example from the resistance of long extension wires
+                /// combined with the input bias current of the
+                /// amplifier. The offset is subtracted from measured
+                /// potentials before conversion, and added to sensed
+                /// voltages.
+                pub const fn with_lead_offset(
+                    self,
+                    lead_offset: Millivolts,
+                ) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.lead_offset = lead_offset;
+                    thermocouple
+                }
+                /// Allows temperatures and potentials outside the range
+                /// over which the reference functions are defined for
+                /// this instance. The result is extrapolated from the
+                /// nearest polynomial, and may be inaccurate.
+                ///
+                /// Out of range values are always allowed when the
+                /// `extrapolate` feature is enabled.
+                pub fn allow_extrapolation(self, allow: bool) -> Self {
+                    self.with_range_mode(match allow {
+                        true => RangeMode::Extrapolate,
+                        false => RangeMode::Panic,
+                    })
+                }
+                /// Clamps temperatures and potentials to the range over
+                /// which the reference functions are defined for this
+                /// instance. An over-range potential senses as the
+                /// temperature at the end of the range, rather than
+                /// panicking.
+                pub fn with_clamping(self) -> Self {
+                    self.with_range_mode(RangeMode::Clamp)
+                }
+                /// Sets the rated operating range of this particular
+                /// probe, from `min` to `max`. With
+                /// [`RangeMode::Panic`], temperatures outside this
+                /// range panic, and the `try_` methods return an error.
+                ///
+                /// This can tighten the range over which the reference
+                /// functions are defined or, with the `extrapolate`
+                /// feature, widen it.
+                pub fn with_operating_range(
+                    self,
+                    min: Celsius,
+                    max: Celsius,
+                ) -> Self {
+                    assert!(min <= max);
+
+                    let mut thermocouple = self;
+                    thermocouple.operating_range = Some((min, max));
+                    thermocouple
+                }
+                /// Sets the handling of temperatures and potentials
+                /// outside the range over which the reference functions
+                /// are defined for this instance.
+                pub fn with_range_mode(self, range_mode: RangeMode) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.range_mode = range_mode;
+                    thermocouple
+                }
+                /// Adds a hysteresis filter with a deadband of `band`,
+                /// so that the sensed temperature only changes when it
+                /// moves by more than `band`. The filtered thermocouple
+                /// holds the last output, so senses with `&mut self`.
+                pub fn with_hysteresis<W>(
+                    self,
+                    band: W,
+                ) -> WithHysteresis<Self, W>
+                where
+                    Self: ThermocoupleCore<W>,
+                    W: Copy
+                        + PartialOrd
+                        + core::ops::Add<Output = W>
+                        + core::ops::Sub<Output = W>,
+                {
+                    WithHysteresis::new(self, band)
+                }
+                /// Sets the temperature scale that sensed temperatures
+                /// are reported on, and that temperatures are given on
+                /// when sensing voltages. The default is ITS-90.
+                pub fn with_scale(self, scale: TemperatureScale) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.scale = scale;
+                    thermocouple
+                }
+                /// Evaluate temperatures and potentials by linear
+                /// interpolation in a lookup table, rather than with
+                /// the reference functions. The table has an entry
+                /// every 10ºC, which is much cheaper to evaluate on
+                /// small microcontrollers. Within the range of the
+                /// inverse function, the error in temperature stays
+                /// within ±1ºC.
+                ///
+                /// The reference junction potential is still
+                /// evaluated with the reference function.
+                #[cfg(feature = "lut")]
+                pub fn with_interpolation(self) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.interpolate = true;
+                    thermocouple
+                }
+                /// Evaluate E(T) for the thermocouple, and check T lies
+                /// within the operating range
+                fn e(&self, t: Celsius) -> Millivolts {
+                    let (min, max) = $mod::TEMPERATURE_RANGE;
+                    let t = match self.range_mode {
+                        RangeMode::Clamp => t.clamp(min, max),
+                        _ => t,
+                    };
+                    self.check_extension_range().unwrap();
+                    self.check_operating_range(t).unwrap();
+
+                    self.e_reference(t)
+                }
+                /// Evaluate E(T) for the thermocouple
+                fn e_reference(&self, t: Celsius) -> Millivolts {
+                    let (min, max) = $mod::TEMPERATURE_RANGE;
+                    let t = match self.range_mode {
+                        RangeMode::Clamp => t.clamp(min, max),
+                        _ => t,
+                    };
+
+                    #[cfg(feature = "lut")]
+                    if self.interpolate {
+                        #[cfg(not(any(feature = "extrapolate")))]
+                        if self.range_mode == RangeMode::Panic {
+                            assert!(t >= min && t <= max);
+                        }
+                        return $mod::TABLE.e(t);
+                    }
+
+                    match self.range_mode {
+                        RangeMode::Panic => $mod::e(t),
+                        _ => $mod::e_unchecked(t),
+                    }
+                }
+                /// Check that `e` lies within the range of the inverse
+                /// function, if the range mode is `Panic`. A potential
+                /// that is within the range of the reference function
+                /// has no inverse rather than being out of range, or
+                /// for Type B is ambiguous.
+                fn check_inverse_range(
+                    &self,
+                    e: Millivolts,
+                ) -> Result<(), ThermocoupleError> {
+                    if self.range_mode != RangeMode::Panic {
+                        return Ok(());
                     }
+                    error::check_range(
+                        e,
+                        $mod::INVERSE_RANGE,
+                        $mod::INVERSE_TOLERANCE,
+                    )
+                    .map_err(|err| {
+                        let (min, max) = $mod::VOLTAGE_RANGE;
+                        if !(e >= min && e <= max) {
+                            ThermocoupleError::Range(err)
+                        } else if ThermocoupleType::$kind
+                            == ThermocoupleType::B
+                        {
+                            ThermocoupleError::Ambiguous(
+                                AmbiguousVoltageError {
+                                    value: e,
+                                    min: err.min,
+                                },
+                            )
+                        } else {
+                            ThermocoupleError::InverseUndefined(err)
+                        }
+                    })
+                }
+                /// Check that `t` lies within the operating range, if
+                /// one is set and the range mode is `Panic`
+                fn check_operating_range(
+                    &self,
+                    t: Celsius,
+                ) -> Result<(), RangeError<Celsius>> {
+                    match self.operating_range {
+                        Some((min, max))
+                            if self.range_mode == RangeMode::Panic
+                                && !(t >= min && t <= max) =>
+                        {
+                            Err(RangeError { value: t, min, max })
+                        }
+                        _ => Ok(()),
+                    }
+                }
+                /// Check that the reference junction lies within the
+                /// range of the extension wire, if connected with
+                /// extension wire and the range mode is `Panic`
+                fn check_extension_range(
+                    &self,
+                ) -> Result<(), RangeError<Celsius>> {
+                    match $mod::EXTENSION_RANGE {
+                        Some((min, max))
+                            if self.beyond_extension_range
+                                && self.range_mode == RangeMode::Panic =>
+                        {
+                            Err(RangeError {
+                                value: self.reference_temperature(),
+                                min,
+                                max,
+                            })
+                        }
+                        _ => Ok(()),
+                    }
+                }
+                /// Note whether the reference junction lies outside the
+                /// range of the extension wire, once when either is set
+                /// rather than for every sample
+                fn with_extension_check(self) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.beyond_extension_range =
+                        match $mod::EXTENSION_RANGE {
+                            Some((min, max)) if self.extension_wire => {
+                                let t = self.reference_temperature();
+                                !(t >= min && t <= max)
+                            }
+                            _ => false,
+                        };
+                    thermocouple
+                }
+                /// Evaluate T for the thermocouple, and check it lies
+                /// within the operating range
+                fn t(&self, e: Millivolts) -> Celsius {
+                    self.check_extension_range().unwrap();
+                    let t = self.t_reference(e);
+                    self.check_operating_range(t).unwrap();
+
+                    t
+                }
+                /// Evaluate T for the thermocouple
+                fn t_reference(&self, e: Millivolts) -> Celsius {
+                    let e = match self.range_mode {
+                        RangeMode::Clamp => {
+                            let (min, max) = $mod::INVERSE_RANGE;
+                            e.clamp(min, max)
+                        }
+                        _ => e,
+                    };
+
+                    #[cfg(feature = "lut")]
+                    if self.interpolate {
+                        #[cfg(not(any(feature = "extrapolate")))]
+                        if self.range_mode == RangeMode::Panic {
+                            error::check_range(
+                                e,
+                                $mod::INVERSE_RANGE,
+                                $mod::INVERSE_TOLERANCE,
+                            )
+                            .unwrap();
+                        }
+                        return $mod::TABLE.t(e);
+                    }
+
+                    match self.range_mode {
+                        RangeMode::Panic => $mod::t(e),
+                        _ => $mod::t_unchecked(e),
+                    }
+                }
+                /// Thermoelectric potential across a single junction
+                /// pair, for a measured potential
+                fn junction_potential(&self, voltage: Millivolts) -> Millivolts {
+                    (voltage - self.lead_offset) / FP::from(self.junctions)
+                }
+                /// Measured potential, for a thermoelectric potential
+                /// across a single junction pair
+                fn measured_potential(&self, e: Millivolts) -> Millivolts {
+                    e * FP::from(self.junctions) + self.lead_offset
+                }
+                /// Apply the temperature scale and calibration to an
+                /// ITS-90 temperature
+                fn calibrate(&self, t: Celsius) -> Celsius {
+                    self.scale.from_its90(t) * self.gain + self.offset
+                }
+                /// Remove the calibration and temperature scale from a
+                /// temperature
+                fn uncalibrate(&self, t: Celsius) -> Celsius {
+                    self.scale.to_its90((t - self.offset) / self.gain)
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, along with the reference
+                /// junction potential and the total potential, for
+                /// diagnostics.
+                pub fn sense_temperature_verbose<W>(
+                    &self,
+                    voltage: Millivolts,
+                ) -> Reading<W>
+                where
+                    Celsius: Into<W>,
+                {
+                    let total_potential = self.junction_potential(voltage)
+                        + self.reference_potential;
+                    let t = self.t(total_potential);
+
+                    Reading {
+                        temperature: self.calibrate(t).into(),
+                        reference_potential: self.reference_potential,
+                        total_potential,
+                    }
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, in integer milli-degrees
+                /// Celsius, or `None` if the temperature is NaN. See
+                /// `Celsius::millidegrees` for the rounding.
+                pub fn sense_temperature_millidegrees(
+                    &self,
+                    voltage: Millivolts,
+                ) -> Option<i32> {
+                    let temperature: Celsius = self.sense_temperature(voltage);
+
+                    temperature.millidegrees()
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential in Celsius, Kelvin and
+                /// Fahrenheit. The inverse function is only evaluated
+                /// once.
+                pub fn sense_temperature_multi(
+                    &self,
+                    voltage: Millivolts,
+                ) -> (Celsius, Kelvin, Fahrenheit) {
+                    let temperature: Celsius = self.sense_temperature(voltage);
+
+                    (temperature, temperature.into(), temperature.into())
+                }
+                /// Returns false if a measured thermoelectric potential
+                /// lies outside the range of the inverse function, for
+                /// example when the ADC reads full-scale because the
+                /// thermocouple is open circuit. This ignores any
+                /// extrapolation, so is suitable for checking every
+                /// sample. It also ignores any operating range set with
+                /// `with_operating_range`; use `in_range` to include it.
+                pub fn is_plausible(&self, voltage: Millivolts) -> bool {
+                    let e = self.junction_potential(voltage)
+                        + self.reference_potential;
+                    let (min, max) = $mod::INVERSE_RANGE;
+                    let tolerance = $mod::INVERSE_TOLERANCE;
+
+                    e.0 >= min.0 - tolerance && e.0 <= max.0 + tolerance
+                }
+                /// Returns true if `sense_temperature` would succeed
+                /// for a given thermoelectric potential, accounting for
+                /// the reference junction potential, the range mode and
+                /// any operating range. Unlike `is_plausible`, this
+                /// follows the `extrapolate` feature.
+                pub fn in_range(&self, voltage: Millivolts) -> bool {
+                    let result: Result<Celsius, ThermocoupleError> =
+                        self.try_sense_temperature(voltage);
+
+                    result.is_ok()
+                }
+                /// Returns the thermocouple temperature for a given
+                /// thermoelectric potential, or `None` if the
+                /// potential is not plausible or the temperature is
+                /// outside the operating range.
+                pub fn sense_temperature_checked<W>(
+                    &self,
+                    voltage: Millivolts,
+                ) -> Option<W>
+                where
+                    Celsius: Into<W>,
+                {
+                    if !self.is_plausible(voltage) {
+                        return None;
+                    }
+                    self.check_extension_range().ok()?;
+
+                    let t = self.t_reference(
+                        self.junction_potential(voltage)
+                            + self.reference_potential,
+                    );
+                    self.check_operating_range(t).ok()?;
+
+                    Some(self.calibrate(t).into())
+                }
+                /// Returns the coefficients of the NIST reference
+                /// function E(T), for each range of temperature in
+                /// increasing order. Coefficient `i` is for `t^i`.
+                pub fn e_coefficients() -> &'static [&'static [FP]] {
+                    $mod::E_COEFFICIENTS
+                }
+                /// Returns the coefficients of the NIST inverse
+                /// function, for each range of E(T) in increasing
+                /// order. Coefficient `i` is for `e^i`.
+                pub fn t_coefficients() -> &'static [&'static [FP]] {
+                    $mod::T_COEFFICIENTS
+                }
+                /// Returns the Seebeck coefficient dE/dT at a given
+                /// thermocouple temperature, in microvolts per degree
+                /// Celsius.
+                pub fn seebeck_coefficient<T>(&self, temperature: T) -> FP
+                where
+                    T: Into<Celsius>,
+                {
+                    $mod::seebeck_coefficient(temperature.into())
+                }
+                /// Returns the temperature resolution at a given
+                /// thermocouple temperature, for an ADC with a
+                /// resolution of `adc_lsb`. This accounts for the
+                /// number of junctions and the calibration gain.
+                pub fn temperature_resolution<T>(
+                    &self,
+                    temperature: T,
+                    adc_lsb: Millivolts,
+                ) -> Celsius
+                where
+                    T: Into<Celsius>,
+                {
+                    let seebeck =
+                        $mod::seebeck_coefficient(temperature.into());
+                    let de_dt = seebeck / 1000.0 // mV/ºC
+                        * FP::from(self.junctions);
+
+                    Celsius((adc_lsb.0 / de_dt * self.gain).abs())
+                }
+                /// Returns the permissible deviation at a given
+                /// thermocouple temperature for an IEC 60584-1
+                /// tolerance class, or `None` if the class is not
+                /// defined at that temperature. With extension wire,
+                /// this includes the deviation of the wire for the same
+                /// IEC 60584-3 class.
+                pub fn tolerance<T>(
+                    &self,
+                    temperature: T,
+                    class: ToleranceClass,
+                ) -> Option<Celsius>
+                where
+                    T: Into<Celsius>,
+                {
+                    let tolerance =
+                        $mod::tolerance(temperature.into(), class)?;
+
+                    match self.extension_wire {
+                        true => Some(
+                            tolerance + $mod::extension_tolerance(class)?,
+                        ),
+                        false => Some(tolerance),
+                    }
+                }
+                /// Checks the reference functions for this type
+                /// against known values, to detect corrupted
+                /// coefficients.
+                pub fn self_test() -> Result<(), SelfTestError> {
+                    let error = SelfTestError {
+                        kind: ThermocoupleType::$kind,
+                    };
+
+                    for &(t, e) in $mod::ANCHORS {
+                        // Error in E(T) as an error in temperature
+                        let s = $mod::seebeck_coefficient(t) / 1000.0;
+                        let e_error = ($mod::e_unchecked(t) - e).0 / s;
+                        let t_error = ($mod::t_unchecked(e) - t).0;
+
+                        // Fails on NaN as well
+                        if !(e_error.abs() <= self_test::TOLERANCE
+                            && t_error.abs() <= self_test::TOLERANCE)
+                        {
+                            return Err(error);
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+            impl ReferenceCache<$Type> {
+                /// Updates the reference junction temperature. E(T) is
+                /// only evaluated again if `reference_temperature`
+                /// differs from the temperature in use by more than the
+                /// threshold. Returns true if it was.
+                pub fn update<T>(&mut self, reference_temperature: T) -> bool
+                where
+                    T: Into<Celsius>,
+                {
+                    let reference = reference_temperature.into();
+                    let stale = self.is_stale(reference);
+
+                    if stale {
+                        self.thermocouple = self
+                            .thermocouple
+                            .with_reference_temperature(reference);
+                        self.last_reference = reference;
+                    }
+
+                    stale
                 }
             }
             impl Default for $Type {
@@ -157,6 +1256,20 @@ macro_rules! thermocouple {
                     $Type::new()
                 }
             }
+            impl ThermocoupleRange for $Type {
+                const TEMPERATURE_RANGE: (Celsius, Celsius) =
+                    $mod::TEMPERATURE_RANGE;
+                const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+                    $mod::VOLTAGE_RANGE;
+                const INVERSE_VOLTAGE_RANGE: (Millivolts, Millivolts) =
+                    $mod::INVERSE_RANGE;
+                const TEMPERATURE_BREAKPOINTS: &'static [Celsius] =
+                    &$mod::TEMPERATURE_BREAKPOINTS;
+                const INVERSE_BREAKPOINTS: &'static [Millivolts] =
+                    &$mod::INVERSE_BREAKPOINTS;
+                const ZERO_VOLTAGE_TEMPERATURE: Celsius =
+                    $mod::ZERO_VOLTAGE_TEMPERATURE;
+            }
 
             $(
                 impl ThermocoupleCore<$unit> for $Type {
@@ -166,12 +1279,76 @@ macro_rules! thermocouple {
                         &self,
                         voltage: Millivolts,
                     ) -> $unit {
-                        $mod::t(voltage + self.reference_potential).into()
+                        let t = self.t(
+                            self.junction_potential(voltage)
+                                + self.reference_potential,
+                        );
+
+                        self.calibrate(t).into()
+                    }
+                    /// Return the thermocouple temperature for a
+                    /// given thermoelectric potential, or an error if
+                    /// the potential is out of range.
+                    fn try_sense_temperature(
+                        &self,
+                        voltage: Millivolts,
+                    ) -> Result<$unit, ThermocoupleError> {
+                        let e = self.junction_potential(voltage)
+                            + self.reference_potential;
+                        self.check_inverse_range(e)?;
+
+                        if let Err(err) = self.check_extension_range() {
+                            return Err(ThermocoupleError::Range(
+                                RangeError {
+                                    value: self.reference_potential,
+                                    min: $mod::e_unchecked(err.min),
+                                    max: $mod::e_unchecked(err.max),
+                                },
+                            ));
+                        }
+
+                        let t = self.t_reference(e);
+                        if let Err(err) = self.check_operating_range(t) {
+                            return Err(ThermocoupleError::Range(
+                                RangeError {
+                                    value: e,
+                                    min: $mod::e_unchecked(err.min),
+                                    max: $mod::e_unchecked(err.max),
+                                },
+                            ));
+                        }
+
+                        Ok(self.calibrate(t).into())
                     }
                     /// Return the thermoelectric potential for a
                     /// given thermocouple temperature.
                     fn sense_voltage(&self, temperature: $unit) -> Millivolts {
-                        $mod::e(temperature.into()) - self.reference_potential
+                        let t = self.uncalibrate(temperature.into());
+
+                        self.measured_potential(
+                            self.e(t) - self.reference_potential,
+                        )
+                    }
+                    /// Return the thermoelectric potential for a
+                    /// given thermocouple temperature, or an error if
+                    /// the temperature is out of range.
+                    fn try_sense_voltage(
+                        &self,
+                        temperature: $unit,
+                    ) -> Result<Millivolts, RangeError<Celsius>> {
+                        let t = self.uncalibrate(temperature.into());
+                        if self.range_mode == RangeMode::Panic {
+                            error::check_temperature_range(
+                                t,
+                                $mod::TEMPERATURE_RANGE,
+                            )?;
+                        }
+                        self.check_extension_range()?;
+                        self.check_operating_range(t)?;
+
+                        Ok(self.measured_potential(
+                            self.e(t) - self.reference_potential,
+                        ))
                     }
                 }
             )+
@@ -179,43 +1356,136 @@ macro_rules! thermocouple {
     };
 }
 
-#[cfg(any(feature = "k-type"))]
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
 thermocouple! {
-    KType, k_type: "Type K thermocouple (chromel-alumel)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+    KType, k_type, K: "Type K thermocouple (chromel-alumel)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
 }
 
 thermocouple! {
-    BType, b_type: "Type B thermocouple (platinum/rhodium alloy)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+    BType, b_type, B: "Type B thermocouple (platinum/rhodium alloy)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
+
+    EType, e_type, E: "Type E thermocouple (chromel-constantan)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
+
+    JType, j_type, J: "Type J thermocouple (iron-constantan)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
+
+    NType, n_type, N: "Type N thermocouple (nicrosil-nisil)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
+
+    RType, r_type, R: "Type R thermocouple (platinum/rhodium alloy)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
 
-    EType, e_type: "Type E thermocouple (chromel-constantan)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+    SType, s_type, S: "Type S thermocouple (platinum/rhodium alloy)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
 
-    JType, j_type: "Type J thermocouple (iron-constantan)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+    TType, t_type, T: "Type T thermocouple (copper-constantan)" =>
+        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle,
+        Romer;
+}
+
+macro_rules! extension_wire {
+    ($($Type:ident, $mod:ident;)*) => {
+        $(
+            impl $Type {
+                /// Sets this thermocouple as connected with extension
+                /// wire, which has the same nominal E(T) but a wider
+                /// tolerance over a limited range of temperatures. The
+                /// supported IEC 60584-3 grades are:
+                ///
+                /// | Grade | Range         | Class 1 | Class 2 |
+                /// |-------|---------------|---------|---------|
+                /// | EX    | -25ºC - 200ºC | ±1.5ºC  | ±2.5ºC  |
+                /// | JX    | -25ºC - 200ºC | ±1.5ºC  | ±2.5ºC  |
+                /// | KX    | -25ºC - 200ºC | ±1.5ºC  | ±2.5ºC  |
+                /// | NX    | -25ºC - 200ºC | ±1.5ºC  | ±2.5ºC  |
+                /// | TX    | -25ºC - 100ºC | ±0.5ºC  | ±1.0ºC  |
+                ///
+                /// The reference junction, at the far end of the
+                /// extension wire, must then lie within the range of
+                /// the grade. Otherwise sensing panics, or returns an
+                /// error from the `try_` methods. Types B, R and S use
+                /// compensating cable instead, so have no equivalent.
+                pub fn with_extension_wire(self) -> Self {
+                    let mut thermocouple = self;
+                    thermocouple.extension_wire = true;
+                    thermocouple.with_extension_check()
+                }
+                /// Returns the range of temperatures that the extension
+                /// wire must stay within, between the thermocouple and
+                /// the reference junction, or `None` if this
+                /// thermocouple is not connected with extension wire.
+                pub fn extension_wire_range(
+                    &self,
+                ) -> Option<(Celsius, Celsius)> {
+                    match self.extension_wire {
+                        true => $mod::EXTENSION_RANGE,
+                        false => None,
+                    }
+                }
+            }
+        )*
+    };
+}
 
-    NType, n_type: "Type N thermocouple (nicrosil-nisil)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
+extension_wire! {
+    KType, k_type;
+}
 
-    RType, r_type: "Type R thermocouple (platinum/rhodium alloy)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+extension_wire! {
+    EType, e_type;
+    JType, j_type;
+    NType, n_type;
+    TType, t_type;
+}
 
-    SType, s_type: "Type S thermocouple (platinum/rhodium alloy)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+impl BType {
+    /// Returns an error if `voltage` is too small to determine the
+    /// temperature unambiguously. The same small potential can arise
+    /// from two temperatures below about 41ºC, and E(T) is too flat
+    /// below 250ºC for the inverse function to be defined.
+    ///
+    /// Unlike `try_sense_temperature`, this check applies regardless
+    /// of the range mode and the `extrapolate` feature.
+    pub fn check_ambiguity(
+        &self,
+        voltage: Millivolts,
+    ) -> Result<(), AmbiguousVoltageError> {
+        let e =
+            self.junction_potential(voltage) + self.reference_potential;
+        let min = b_type::INVERSE_RANGE.0;
 
-    TType, t_type: "Type T thermocouple (copper-constantan)" =>
-        Celsius, Kelvin, Fahrenheit, Rankine, Reaumur;
+        match e.0 < min.0 - b_type::INVERSE_TOLERANCE {
+            true => Err(AmbiguousVoltageError { value: e, min }),
+            false => Ok(()),
+        }
+    }
 }
 
 /// A convenience wrapper to allow the user to import all the traits
 /// and structures required.
 pub mod prelude {
     pub use crate::units::FPExt as _thermocouple_FPExt;
-    pub use crate::ThermocoupleCore;
     pub use crate::{
-        Celsius, Fahrenheit, Kelvin, Millivolts, Rankine, Reaumur,
+        Celsius, Delisle, Fahrenheit, Kelvin, Microvolts, Millivolts,
+        Newton, Ohms, Rankine, Reaumur, Romer,
     };
+    pub use crate::{Temperature, ThermocoupleCore, ThermocoupleRange};
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    pub use crate::KType;
+    pub use crate::{BType, EType, JType, NType, RType, SType, TType};
+    pub use crate::{Thermocouple, ThermocoupleType};
 }
 
 #[cfg(test)]
@@ -238,13 +1508,18 @@ mod tests {
                     let temperature: Celsius =
                         $Type::new().sense_temperature(Millivolts(1.1));
                     println!("{}", temperature.0);
-                    compare(temperature.0, $result, 0.05); // ±0.05ºC tolerance
+
+                    #[cfg(not(feature = "reduced-order"))]
+                    const TOLERANCE: FP = 0.05; // ±0.05ºC tolerance
+                    #[cfg(feature = "reduced-order")]
+                    const TOLERANCE: FP = 1.0; // ±1ºC tolerance
+                    compare(temperature.0, $result, TOLERANCE);
                 }
             )*
         }
     }
 
-    #[cfg(any(feature = "k-type"))]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
     test_temperature_default! {
         KType, k_type_1_1m_v, 51.870;
     }
@@ -257,4 +1532,1158 @@ mod tests {
         SType, s_type_1_1m_v, 176.278;
         TType, t_type_1_1m_v, 51.312;
     }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[test]
+    /// Test the ranges are available as associated constants
+    fn k_type_ranges() {
+        assert_eq!(KType::TEMPERATURE_RANGE.0, Celsius(-270.0));
+        assert_eq!(KType::TEMPERATURE_RANGE.1, Celsius(1372.0));
+        assert_eq!(KType::VOLTAGE_RANGE.0, Millivolts(-6.458));
+        assert_eq!(KType::VOLTAGE_RANGE.1, Millivolts(54.886));
+        assert_eq!(KType::INVERSE_VOLTAGE_RANGE.0, Millivolts(-5.891));
+        assert_eq!(KType::INVERSE_VOLTAGE_RANGE.1, Millivolts(54.886));
+    }
+
+    #[test]
+    /// Test the ends of the inverse range can be sensed directly
+    fn inverse_voltage_range() {
+        let (min, max) = NType::INVERSE_VOLTAGE_RANGE;
+
+        let _: Celsius = NType::direct().sense_temperature(min);
+        let _: Celsius = NType::direct().sense_temperature(max);
+        assert!(min >= NType::VOLTAGE_RANGE.0);
+        assert!(max <= NType::VOLTAGE_RANGE.1);
+    }
+
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test thermocouples can be constructed at compile time
+    fn const_new() {
+        const THERMOCOUPLE: JType = JType::new();
+
+        let temperature: Celsius =
+            THERMOCOUPLE.sense_temperature(Millivolts(1.1));
+        compare(temperature.0, 46.058, 0.05); // ±0.05ºC tolerance
+    }
+
+    #[cfg(all(
+        any(feature = "k-type", feature = "no-libm"),
+        not(feature = "reduced-order")
+    ))]
+    #[test]
+    /// Test the Type K reference junction potential is evaluated at
+    /// compile time
+    fn k_type_const_new() {
+        const REF: Millivolts = k_type::DEFAULT_REFERENCE_POTENTIAL;
+        const THERMOCOUPLE: KType = KType::new();
+
+        compare(REF.0, 1.000, 0.0005); // NIST table at 25ºC
+        compare(REF.0, k_type::e(Celsius(25.0)).0, 1e-6);
+
+        let temperature: Celsius =
+            THERMOCOUPLE.sense_temperature(Millivolts(0.0));
+        compare(temperature.0, 25.0, 0.05); // ±0.05ºC tolerance
+    }
+
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test a direct thermocouple applies the inverse function to the
+    /// voltage unmodified
+    fn direct_zero_voltage() {
+        let temperature: Celsius =
+            JType::direct().sense_temperature(Millivolts(0.0));
+        assert_eq!(temperature, Celsius(0.0));
+
+        let voltage = TType::direct().sense_voltage(Celsius(100.0));
+        compare(voltage.0, 4.279, 0.0005); // NIST table value
+    }
+
+    #[test]
+    /// Test IEC 60584-1 tolerances, for example Type K Class 2 is
+    /// ±0.75% at 1000ºC
+    fn tolerance() {
+        #[cfg(any(feature = "k-type", feature = "no-libm"))]
+        {
+            let k = KType::new();
+            assert_eq!(
+                k.tolerance(Celsius(1000.0), ToleranceClass::Two),
+                Some(Celsius(7.5))
+            );
+            assert_eq!(
+                k.tolerance(Celsius(100.0), ToleranceClass::One),
+                Some(Celsius(1.5))
+            );
+        }
+
+        assert_eq!(
+            TType::new()
+                .tolerance(Celsius(-100.0), ToleranceClass::Three),
+            Some(Celsius(1.5))
+        );
+        assert_eq!(
+            SType::new()
+                .tolerance(Celsius(1200.0), ToleranceClass::One),
+            Some(Celsius(1.3))
+        );
+        assert_eq!(
+            JType::new().tolerance(Celsius(0.0), ToleranceClass::Three),
+            None
+        );
+        assert_eq!(
+            BType::new().tolerance(Celsius(500.0), ToleranceClass::Two),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "use_serde")]
+    /// Test a configured thermocouple survives a round trip through JSON
+    fn serde_round_trip() {
+        let thermocouple =
+            NType::new().with_reference_temperature(Celsius(0.0));
+
+        let json = serde_json::to_string(&thermocouple).unwrap();
+        let restored: NType = serde_json::from_str(&json).unwrap();
+
+        let voltage = Millivolts(1.1);
+        let expected: Celsius = thermocouple.sense_temperature(voltage);
+        let temperature: Celsius = restored.sense_temperature(voltage);
+        assert_eq!(temperature, expected);
+    }
+
+    #[cfg(feature = "use_embedded_hal")]
+    mod adc {
+        use embedded_hal::adc::{Channel, OneShot};
+
+        /// ADC that returns a fixed 12-bit reading
+        pub struct MockAdc(pub u16);
+        pub struct MockPin;
+
+        impl Channel<MockAdc> for MockPin {
+            type ID = u8;
+            fn channel() -> u8 {
+                0
+            }
+        }
+        impl OneShot<MockAdc, u16, MockPin> for MockAdc {
+            type Error = ();
+            fn read(&mut self, _: &mut MockPin) -> nb::Result<u16, ()> {
+                Ok(self.0)
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "use_embedded_hal")]
+    /// Test reading from an ADC gives the same result as converting
+    /// the voltage directly
+    fn read_temperature() {
+        use adc::{MockAdc, MockPin};
+
+        let thermocouple = JType::new();
+        // 1µV per count
+        let adc_to_mv = |raw: u16| Millivolts(FP::from(raw) / 1000.0);
+
+        let temperature: Celsius = thermocouple
+            .read_temperature(
+                &mut MockAdc(1100),
+                &mut MockPin,
+                adc_to_mv,
+            )
+            .unwrap();
+        let expected: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        assert_eq!(temperature, expected);
+    }
+
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test sensing with a reference temperature given per reading
+    /// matches an instance constructed with that reference temperature
+    fn sense_temperature_with_reference() {
+        let reference = Celsius(10.0);
+        let thermocouple =
+            EType::new().with_reference_temperature(reference);
+
+        let expected: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        let temperature: Celsius = EType::new()
+            .sense_temperature_with_reference(
+                Millivolts(1.1),
+                reference,
+            );
+        assert_eq!(temperature, expected);
+
+        compare(
+            EType::cold_junction_potential(Celsius(0.0)).0,
+            0.0,
+            1e-9,
+        );
+    }
+
+    #[test]
+    /// Test a thermopile reads the same temperature for a multiple of
+    /// the voltage of a single junction pair
+    fn with_junction_count() {
+        let single = RType::new();
+        let stack = RType::new().with_junction_count(4);
+
+        let expected: Celsius =
+            single.sense_temperature(Millivolts(1.1));
+        let temperature: Celsius =
+            stack.sense_temperature(Millivolts(4.4));
+        compare(temperature.0, expected.0, 1e-9);
+
+        let voltage = stack.sense_voltage(Celsius(500.0));
+        compare(
+            voltage.0,
+            4.0 * single.sense_voltage(Celsius(500.0)).0,
+            1e-9,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_junction_count_zero() {
+        let _ = RType::new().with_junction_count(0);
+    }
+
+    #[test]
+    /// Test strict and lenient instances of the same type side by side
+    fn allow_extrapolation() {
+        let strict = TType::new();
+        let lenient = TType::new().allow_extrapolation(true);
+
+        let temperature: Result<Celsius, _> =
+            lenient.try_sense_temperature(Millivolts(25.0));
+        assert!(temperature.is_ok());
+        let _ = lenient.sense_voltage(Celsius(450.0));
+
+        #[cfg(not(any(feature = "extrapolate")))]
+        {
+            let temperature: Result<Celsius, _> =
+                strict.try_sense_temperature(Millivolts(25.0));
+            assert!(temperature.is_err());
+        }
+        let temperature: Celsius =
+            strict.sense_temperature(Millivolts(1.1));
+        let expected: Celsius =
+            lenient.sense_temperature(Millivolts(1.1));
+        assert_eq!(temperature, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(any(feature = "extrapolate")))]
+    fn strict_out_of_range() {
+        let _ = TType::new().sense_voltage(Celsius(450.0));
+    }
+
+    #[test]
+    /// Test the reference temperature can be given in other units
+    fn with_reference_units() {
+        let celsius =
+            SType::new().with_reference_temperature(Celsius(0.0));
+        let kelvin = SType::new().with_reference_kelvin(Kelvin(273.15));
+        let fahrenheit =
+            SType::new().with_reference_fahrenheit(Fahrenheit(32.0));
+
+        let expected: Celsius =
+            celsius.sense_temperature(Millivolts(1.1));
+        for thermocouple in &[kelvin, fahrenheit] {
+            let temperature: Celsius =
+                thermocouple.sense_temperature(Millivolts(1.1));
+            compare(temperature.0, expected.0, 1e-4);
+        }
+    }
+
+    #[test]
+    /// Test a full-scale reading from an open circuit thermocouple is
+    /// flagged
+    fn is_plausible() {
+        let thermocouple = JType::new();
+
+        assert!(thermocouple.is_plausible(Millivolts(1.1)));
+        assert!(!thermocouple.is_plausible(Millivolts(3300.0)));
+        assert!(thermocouple
+            .sense_temperature_checked::<Celsius>(Millivolts(3300.0))
+            .is_none());
+
+        let expected: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        assert_eq!(
+            thermocouple.sense_temperature_checked(Millivolts(1.1)),
+            Some(expected)
+        );
+    }
+
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test the verbose reading exposes the reference potential
+    fn sense_temperature_verbose() {
+        let thermocouple = JType::new();
+        let reading: Reading<Celsius> =
+            thermocouple.sense_temperature_verbose(Millivolts(2.0));
+
+        let expected: Celsius =
+            thermocouple.sense_temperature(Millivolts(2.0));
+        assert_eq!(reading.temperature, expected);
+        compare(reading.reference_potential.0, 1.277, 0.0005);
+        compare(
+            reading.total_potential.0,
+            2.0 + reading.reference_potential.0,
+            1e-9,
+        );
+    }
+
+    #[test]
+    /// Test two close readings within the deadband give an identical
+    /// output
+    fn with_hysteresis() {
+        let mut thermocouple =
+            EType::new().with_hysteresis(Celsius(0.5));
+
+        let first: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        let second: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.105));
+        assert_eq!(first, second);
+
+        let third: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.2));
+        assert!(third > first);
+    }
+
+    #[test]
+    /// Test temperatures on the IPTS-68 scale are offset from ITS-90 by
+    /// a few hundredths of a degree
+    fn with_scale() {
+        let its90 = RType::new();
+        let ipts68 = RType::new().with_scale(TemperatureScale::Ipts68);
+
+        let voltage = its90.sense_voltage(Celsius(500.0));
+        let t90: Celsius = its90.sense_temperature(voltage);
+        let t68: Celsius = ipts68.sense_temperature(voltage);
+        compare(t68.0 - t90.0, 0.079, 0.001);
+
+        compare(
+            ipts68.sense_voltage(t68).0,
+            its90.sense_voltage(t90).0,
+            1e-6,
+        );
+    }
+
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test the coefficient arrays are exposed in order
+    fn coefficients() {
+        let e = TType::e_coefficients();
+        assert_eq!(e.len(), 2);
+        assert_eq!(e[0].len(), 15);
+        assert_eq!(e[1].len(), 9);
+        assert_eq!(e[1][1], 0.387481063640E-01);
+
+        let t = RType::t_coefficients();
+        assert_eq!(t.len(), 4);
+        assert!(t.iter().all(|c| c.len() == 11));
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test an over-range potential senses as the end of the range
+    fn with_clamping() {
+        let thermocouple = KType::new().with_clamping();
+
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(100.0));
+        let expected: Celsius =
+            KType::direct().sense_temperature(Millivolts(54.886));
+        assert_eq!(temperature, expected);
+
+        let voltage = thermocouple.sense_voltage(Celsius(2000.0));
+        compare(
+            voltage.0,
+            thermocouple.sense_voltage(Celsius(1372.0)).0,
+            1e-9,
+        );
+    }
+
+    #[test]
+    /// Test a calibration offset shifts the sensed temperature, and is
+    /// removed again when sensing voltage
+    fn with_calibration() {
+        let thermocouple =
+            TType::new().with_calibration(Celsius(1.5), 1.0);
+
+        let raw: Celsius =
+            TType::new().sense_temperature(Millivolts(1.1));
+        let calibrated: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        compare(calibrated.0, raw.0 + 1.5, 1e-4);
+
+        let voltage = thermocouple.sense_voltage(calibrated);
+        compare(voltage.0, 1.1, 0.005); // Inverse function error
+    }
+
+    #[test]
+    #[cfg(feature = "lut")]
+    /// Test interpolation stays close to the reference functions
+    fn with_interpolation() {
+        let thermocouple = JType::new().with_interpolation();
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        compare(temperature.0, 46.058, 1.0); // ±1ºC tolerance
+
+        let voltage = thermocouple.sense_voltage(Celsius(46.058));
+        compare(voltage.0, 1.1, 0.05);
+    }
+
+    #[test]
+    /// Test batch conversion gives the same results as converting
+    /// element-wise
+    fn sense_temperature_slice() {
+        let thermocouple = NType::new();
+        let voltages =
+            [Millivolts(0.0), Millivolts(1.1), Millivolts(2.0)];
+        let mut temperatures = [Celsius(0.0); 3];
+
+        thermocouple
+            .sense_temperature_slice(&voltages, &mut temperatures);
+
+        for (voltage, temperature) in voltages.iter().zip(&temperatures)
+        {
+            assert_eq!(
+                *temperature,
+                thermocouple.sense_temperature(*voltage)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    /// Test the owned batch conversion gives the same results as
+    /// converting element-wise
+    fn sense_temperature_vec() {
+        let thermocouple = NType::new();
+        let voltages = [Millivolts(1.1); 1000];
+
+        let temperatures: alloc::vec::Vec<Celsius> =
+            thermocouple.sense_temperature_vec(&voltages);
+
+        assert_eq!(temperatures.len(), voltages.len());
+        for (voltage, temperature) in voltages.iter().zip(&temperatures)
+        {
+            assert_eq!(
+                *temperature,
+                thermocouple.sense_temperature(*voltage)
+            );
+        }
+    }
+
+    #[cfg(feature = "use_heapless")]
+    #[test]
+    /// Test the heapless batch conversion rejects more potentials
+    /// than its capacity
+    fn sense_temperature_heapless() {
+        let thermocouple = NType::new();
+        let voltages = [Millivolts(1.1); 20];
+
+        let temperatures: heapless::Vec<Celsius, 20> =
+            thermocouple.sense_temperature_heapless(&voltages).unwrap();
+        assert_eq!(temperatures.len(), 20);
+        assert_eq!(
+            temperatures[19],
+            thermocouple.sense_temperature(Millivolts(1.1))
+        );
+
+        let result: Result<heapless::Vec<Celsius, 16>, _> =
+            thermocouple.sense_temperature_heapless(&voltages);
+        assert_eq!(
+            result,
+            Err(CapacityError {
+                len: 20,
+                capacity: 16
+            })
+        );
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test a K-type temperature in integer milli-degrees
+    fn k_type_millidegrees() {
+        // 99.963ºC
+        let millidegrees = KType::direct()
+            .sense_temperature_millidegrees(Millivolts(4.096));
+        assert_eq!(millidegrees, Some(99963));
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test the Seebeck coefficient against the NIST value at 0ºC
+    fn k_type_seebeck_coefficient() {
+        let seebeck = KType::new().seebeck_coefficient(Celsius(0.0));
+        compare(seebeck, 39.450, 0.001); // ±0.001µV/ºC tolerance
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test the zero voltage temperature gives zero potential
+    fn k_type_zero_voltage_temperature() {
+        let voltage = KType::direct()
+            .sense_voltage(KType::ZERO_VOLTAGE_TEMPERATURE);
+        compare(voltage.0, 0.0, 0.0005); // ±0.5µV tolerance
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[cfg(not(feature = "extrapolate"))]
+    #[test]
+    /// Test a K-type potential below -200ºC, where NIST does not
+    /// define the inverse function
+    fn k_type_no_inverse_below_200() {
+        let thermocouple = KType::direct();
+        let voltage = thermocouple.sense_voltage(Celsius(-250.0));
+
+        let err = ThermocoupleCore::<Celsius>::try_sense_temperature(
+            &thermocouple,
+            voltage,
+        )
+        .unwrap_err();
+
+        // Within E(T), but not within the inverse
+        assert!(voltage > KType::VOLTAGE_RANGE.0);
+        match err {
+            ThermocoupleError::InverseUndefined(err) => {
+                assert_eq!(err.value, voltage);
+                assert_eq!(err.min, KType::INVERSE_VOLTAGE_RANGE.0);
+            }
+            _ => panic!("Expected an undefined inverse"),
+        }
+
+        // Beyond E(T) altogether
+        let err = ThermocoupleCore::<Celsius>::try_sense_temperature(
+            &thermocouple,
+            Millivolts(-10.0),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ThermocoupleError::Range(_)));
+    }
+
+    #[cfg(all(
+        any(feature = "k-type", feature = "no-libm"),
+        feature = "f64"
+    ))]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test the refined inverse reproduces the reference function
+    fn k_type_refined() {
+        let thermocouple = KType::new();
+        let voltage = thermocouple.sense_voltage(Celsius(512.3));
+
+        let t: Celsius =
+            thermocouple.sense_temperature_refined(voltage);
+        compare(t.0, 512.3, 1e-6);
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[test]
+    /// Test the free function with a 0ºC reference
+    fn k_type_celsius_from_millivolts() {
+        let expected: Celsius =
+            KType::direct().sense_temperature(Millivolts(1.1));
+
+        assert_eq!(
+            k_type::celsius_from_millivolts(Millivolts(1.1)),
+            Ok(expected)
+        );
+        #[cfg(not(feature = "extrapolate"))]
+        assert!(
+            k_type::celsius_from_millivolts(Millivolts(100.0)).is_err()
+        );
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[test]
+    /// Test the name and letter constants
+    fn k_type_name() {
+        assert_eq!(KType::NAME, "Type K thermocouple (chromel-alumel)");
+        assert_eq!(KType::LETTER, 'K');
+        assert_eq!(BType::LETTER, 'B');
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[test]
+    /// Test the resolution for a 1µV ADC at 0ºC
+    fn k_type_temperature_resolution() {
+        let resolution = KType::new()
+            .temperature_resolution(Celsius(0.0), Millivolts(0.001));
+        compare(resolution.0, 0.0253, 0.0005);
+
+        // Twice the junctions, half the resolution
+        let resolution = KType::new()
+            .with_junction_count(2)
+            .temperature_resolution(Celsius(0.0), Millivolts(0.001));
+        compare(resolution.0, 0.0127, 0.0005);
+    }
+
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test a K-type thermocouple selected at runtime
+    fn k_type_from_letter() {
+        let temperature: Celsius = Thermocouple::from_letter('K')
+            .unwrap()
+            .sense_temperature(Millivolts(1.1));
+        compare(temperature.0, 51.870, 0.05); // ±0.05ºC tolerance
+    }
+
+    #[cfg(all(
+        any(feature = "k-type", feature = "no-libm"),
+        not(feature = "extrapolate")
+    ))]
+    #[test]
+    /// Test an out of range voltage returns an error rather than
+    /// panicking
+    fn try_sense_temperature_out_of_range() {
+        let result: Result<Celsius, _> =
+            KType::new().try_sense_temperature(Millivolts(100.0));
+
+        match result {
+            Err(ThermocoupleError::Range(RangeError {
+                value,
+                min,
+                max,
+            })) => {
+                assert!(value > max);
+                assert_eq!(min, Millivolts(-5.891));
+                assert_eq!(max, Millivolts(54.886));
+            }
+            _ => panic!("Expected a range error"),
+        }
+    }
+
+    #[cfg(all(
+        any(feature = "k-type", feature = "no-libm"),
+        not(feature = "extrapolate")
+    ))]
+    #[test]
+    /// Test an out of range temperature returns an error rather than
+    /// panicking
+    fn try_sense_voltage_out_of_range() {
+        match KType::new().try_sense_voltage(Celsius(2000.0)) {
+            Err(RangeError { value, min, max }) => {
+                assert_eq!(value, Celsius(2000.0));
+                assert_eq!(min, Celsius(-270.0));
+                assert_eq!(max, Celsius(1372.0));
+            }
+            Ok(_) => panic!("Expected a range error"),
+        }
+    }
+
+    #[test]
+    /// Test the reference potential is subtracted exactly
+    fn with_reference_potential() {
+        let thermocouple =
+            JType::new().with_reference_potential(Millivolts(1.0));
+        let voltage = JType::direct().sense_voltage(Celsius(100.0));
+
+        assert_eq!(
+            thermocouple.sense_voltage(Celsius(100.0)),
+            voltage - Millivolts(1.0)
+        );
+    }
+
+    #[test]
+    /// Test a lead offset shifts the sensed temperature
+    fn lead_offset() {
+        let thermocouple = JType::new();
+        let compensated =
+            thermocouple.with_lead_offset(Millivolts(0.01));
+
+        let t: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        let t_compensated: Celsius =
+            compensated.sense_temperature(Millivolts(1.11));
+        compare(t.0, t_compensated.0, 1e-4);
+
+        let voltage = compensated.sense_voltage(t);
+        let expected = thermocouple.sense_voltage(t) + Millivolts(0.01);
+        compare(voltage.0, expected.0, 1e-6);
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test the operating range limits the sensed temperature
+    fn k_type_operating_range() {
+        let thermocouple = KType::new()
+            .with_operating_range(Celsius(0.0), Celsius(800.0));
+        let voltage = KType::new().sense_voltage(Celsius(900.0));
+
+        assert!(thermocouple
+            .try_sense_voltage(Celsius(900.0))
+            .is_err());
+        let t: Result<Celsius, _> =
+            thermocouple.try_sense_temperature(voltage);
+        assert!(t.is_err());
+
+        let voltage = KType::new().sense_voltage(Celsius(500.0));
+        let t: Celsius = thermocouple.sense_temperature(voltage);
+        compare(t.0, 500.0, 1.0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test a checked read beyond the operating range returns `None`
+    fn k_type_operating_range_checked() {
+        let thermocouple = KType::new()
+            .with_operating_range(Celsius(0.0), Celsius(800.0));
+
+        assert!(thermocouple.is_plausible(Millivolts(37.0)));
+        assert!(thermocouple
+            .sense_temperature_checked::<Celsius>(Millivolts(37.0))
+            .is_none());
+        assert!(thermocouple
+            .sense_temperature_checked::<Celsius>(Millivolts(10.0))
+            .is_some());
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[should_panic]
+    /// Test sensing beyond the operating range panics
+    fn k_type_operating_range_panics() {
+        let thermocouple = KType::new()
+            .with_operating_range(Celsius(0.0), Celsius(800.0));
+
+        let _ = thermocouple.sense_voltage(Celsius(900.0));
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test the default reference temperature matches `new`
+    fn k_type_default_reference() {
+        assert_eq!(KType::DEFAULT_REFERENCE, Celsius(25.0));
+
+        let expected = KType::new()
+            .with_reference_temperature(KType::DEFAULT_REFERENCE);
+        let temperature: Celsius =
+            KType::new().sense_temperature(Millivolts(1.1));
+        let expected: Celsius =
+            expected.sense_temperature(Millivolts(1.1));
+        compare(temperature.0, expected.0, 1e-6);
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test the curve spans the temperature range without overshooting
+    fn k_type_curve() {
+        let mut curve = KType::curve(Celsius(100.0));
+
+        assert_eq!(
+            curve.next(),
+            Some((Celsius(-270.0), k_type::e(Celsius(-270.0))))
+        );
+        let (t, e) = curve.last().unwrap();
+        assert_eq!(t, Celsius(1372.0));
+        assert_eq!(e, k_type::e(Celsius(1372.0)));
+
+        assert_eq!(KType::curve(Celsius(100.0)).count(), 18);
+    }
+
+    #[test]
+    /// Test the curve ends exactly at the top of the range when the
+    /// step does not divide it
+    fn j_type_curve_partial_step() {
+        let (min, max) = j_type::TEMPERATURE_RANGE;
+        let step = Celsius(7.0);
+        assert!((max.0 - min.0) % step.0 != 0.0);
+
+        let curve: std::vec::Vec<_> = JType::curve(step).collect();
+        assert_eq!(curve.len(), 203);
+        assert_eq!(curve[201].0, Celsius(min.0 + 201.0 * step.0));
+        assert_eq!(curve[202], (max, j_type::e(max)));
+    }
+
+    #[test]
+    /// Test small Type B potentials are reported as ambiguous
+    fn b_type_ambiguity() {
+        let thermocouple = BType::new();
+
+        let err = thermocouple.check_ambiguity(Millivolts(0.1));
+        assert_eq!(err.unwrap_err().min, Millivolts(0.291));
+        assert!(thermocouple.check_ambiguity(Millivolts(1.0)).is_ok());
+
+        // Also with clamping, where sensing would not fail
+        let clamping = thermocouple.with_clamping();
+        assert!(clamping.check_ambiguity(Millivolts(0.1)).is_err());
+    }
+
+    #[cfg(not(feature = "extrapolate"))]
+    #[test]
+    /// Test sensing a small Type B potential returns the ambiguity
+    fn b_type_try_sense_temperature_ambiguous() {
+        let thermocouple = BType::direct();
+
+        let result: Result<Celsius, _> =
+            thermocouple.try_sense_temperature(Millivolts(0.1));
+        assert_eq!(
+            result,
+            Err(ThermocoupleError::Ambiguous(
+                thermocouple
+                    .check_ambiguity(Millivolts(0.1))
+                    .unwrap_err()
+            ))
+        );
+
+        // Below E(T) altogether
+        let result: Result<Celsius, _> =
+            thermocouple.try_sense_temperature(Millivolts(-1.0));
+        assert!(matches!(result, Err(ThermocoupleError::Range(_))));
+    }
+
+    #[test]
+    /// Test the range check accounts for the reference junction
+    fn in_range() {
+        let (_, max) = JType::INVERSE_VOLTAGE_RANGE;
+        let thermocouple = JType::new();
+        let direct = JType::direct();
+
+        assert!(thermocouple.in_range(Millivolts(1.1)));
+        assert!(direct.in_range(max));
+        assert_eq!(
+            thermocouple.in_range(max),
+            cfg!(feature = "extrapolate")
+        );
+        assert!(thermocouple.with_clamping().in_range(max));
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test extension wire widens the tolerance
+    fn k_type_extension_wire() {
+        let thermocouple = KType::new();
+        let extended = thermocouple.with_extension_wire();
+
+        assert_eq!(thermocouple.extension_wire_range(), None);
+        assert_eq!(
+            extended.extension_wire_range(),
+            Some((Celsius(-25.0), Celsius(200.0)))
+        );
+
+        let class = ToleranceClass::Two;
+        let tolerance = thermocouple.tolerance(Celsius(500.0), class);
+        assert_eq!(
+            extended.tolerance(Celsius(500.0), class),
+            tolerance.map(|t| t + Celsius(2.5))
+        );
+        assert_eq!(
+            extended.tolerance(Celsius(500.0), ToleranceClass::Three),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test extension wire restricts the reference junction
+    /// temperature
+    fn k_type_extension_wire_range() {
+        let thermocouple = KType::new().with_extension_wire();
+        let voltage = Millivolts(10.0);
+
+        let ok: Result<Celsius, _> =
+            thermocouple.try_sense_temperature(voltage);
+        assert!(ok.is_ok());
+        assert!(thermocouple.in_range(voltage));
+
+        let hot =
+            thermocouple.with_reference_temperature(Celsius(250.0));
+        let err: Result<Celsius, _> =
+            hot.try_sense_temperature(voltage);
+        match err {
+            Err(ThermocoupleError::Range(err)) => assert_eq!(
+                err.value,
+                KType::cold_junction_potential(Celsius(250.0))
+            ),
+            _ => panic!("Expected a range error"),
+        }
+        assert!(!hot.in_range(voltage));
+        assert!(hot
+            .sense_temperature_checked::<Celsius>(voltage)
+            .is_none());
+        assert!(hot.try_sense_voltage(Celsius(300.0)).is_err());
+
+        let _: Celsius = hot.with_clamping().sense_temperature(voltage);
+
+        // Checked whichever is set first, and for a potential
+        let hot =
+            KType::at_reference(Celsius(250.0)).with_extension_wire();
+        assert!(!hot.in_range(voltage));
+        let hot = thermocouple
+            .with_reference_potential(k_type::e(Celsius(250.0)));
+        assert!(!hot.in_range(voltage));
+        assert!(hot
+            .with_reference_temperature(Celsius(25.0))
+            .in_range(voltage));
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    #[should_panic]
+    /// Test sensing with the reference junction beyond the extension
+    /// wire range panics
+    fn k_type_extension_wire_range_panic() {
+        let _: Celsius = KType::new()
+            .with_extension_wire()
+            .with_reference_temperature(Celsius(250.0))
+            .sense_temperature(Millivolts(10.0));
+    }
+
+    #[test]
+    /// Test the reference junction potential is only updated beyond
+    /// the threshold
+    fn reference_cache() {
+        let mut cache = JType::new()
+            .with_reference_cache(Celsius(20.0), Celsius(0.1));
+
+        assert!(!cache.update(Celsius(20.05)));
+        assert_eq!(cache.last_reference(), Celsius(20.0));
+        assert!(cache.update(Celsius(20.2)));
+        assert_eq!(cache.last_reference(), Celsius(20.2));
+
+        let expected: Celsius = JType::new()
+            .with_reference_temperature(Celsius(20.2))
+            .sense_temperature(Millivolts(1.1));
+        let temperature: Celsius =
+            cache.sense_temperature(Millivolts(1.1));
+        assert_eq!(temperature, expected);
+    }
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test a Type T thermocouple measuring the reference junction of a
+    /// Type K thermocouple
+    fn k_type_reference_from() {
+        let cold_junction = TType::direct();
+        let cold_junction_voltage =
+            cold_junction.sense_voltage(Celsius(20.0));
+        let reference: Celsius =
+            cold_junction.sense_temperature(cold_junction_voltage);
+
+        let thermocouple = KType::new()
+            .with_reference_from(&cold_junction, cold_junction_voltage);
+        let expected =
+            KType::new().with_reference_temperature(reference);
+
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(10.0));
+        let expected: Celsius =
+            expected.sense_temperature(Millivolts(10.0));
+        assert_eq!(temperature, expected);
+        compare(reference.0, 20.0, 0.1);
+    }
+
+    #[test]
+    /// Test the temperature on each scale agrees with sensing it
+    /// directly
+    fn sense_temperature_multi() {
+        let thermocouple = JType::new();
+        let (celsius, kelvin, fahrenheit) =
+            thermocouple.sense_temperature_multi(Millivolts(1.1));
+
+        let expected: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        assert_eq!(celsius, expected);
+        let expected: Kelvin =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        assert_eq!(kelvin, expected);
+        let expected: Fahrenheit =
+            thermocouple.sense_temperature(Millivolts(1.1));
+        assert_eq!(fahrenheit, expected);
+    }
+
+    #[test]
+    /// Test constructing with a reference temperature
+    fn at_reference() {
+        let thermocouple = JType::at_reference(Celsius(0.0));
+        let expected =
+            JType::new().with_reference_temperature(Celsius(0.0));
+
+        assert_eq!(
+            thermocouple.sense_voltage(Celsius(100.0)),
+            expected.sense_voltage(Celsius(100.0))
+        );
+    }
+
+    #[test]
+    /// Test the reference temperature can be read back
+    fn reference_temperature() {
+        #[cfg(not(feature = "reduced-order"))]
+        const TOLERANCE: FP = 0.05;
+        #[cfg(feature = "reduced-order")]
+        const TOLERANCE: FP = 1.0;
+
+        let thermocouple =
+            JType::new().with_reference_temperature(Celsius(30.0));
+        compare(
+            thermocouple.reference_temperature().0,
+            30.0,
+            TOLERANCE,
+        );
+
+        let thermocouple = Thermocouple::T(
+            TType::new().with_reference_temperature(Celsius(30.0)),
+        );
+        compare(
+            thermocouple.reference_temperature().0,
+            30.0,
+            TOLERANCE,
+        );
+
+        // The potential set directly is inverted
+        let thermocouple = JType::new()
+            .with_reference_potential(j_type::e(Celsius(30.0)));
+        compare(
+            thermocouple.reference_temperature().0,
+            30.0,
+            TOLERANCE,
+        );
+    }
+
+    #[test]
+    /// Test the Type B reference temperature is read back exactly,
+    /// although the inverse function is undefined near room
+    /// temperature
+    fn b_type_reference_temperature() {
+        assert_eq!(BType::new().reference_temperature(), Celsius(25.0));
+        assert_eq!(
+            BType::at_reference(Celsius(30.0)).reference_temperature(),
+            Celsius(30.0)
+        );
+        assert_eq!(
+            BType::direct().reference_temperature(),
+            Celsius(0.0)
+        );
+    }
+
+    #[test]
+    /// Test sensing from a raw cold junction voltage matches converting
+    /// the cold junction voltage to a temperature first
+    fn sense_temperature_from_raw() {
+        let thermocouple = JType::new();
+        let cold_junction_voltage = Millivolts(1.277);
+
+        let reference: Celsius =
+            JType::direct().sense_temperature(cold_junction_voltage);
+        let expected: Celsius = thermocouple
+            .sense_temperature_with_reference(
+                Millivolts(4.0),
+                reference,
+            );
+        let temperature: Celsius = thermocouple
+            .sense_temperature_from_raw(
+                Millivolts(4.0),
+                cold_junction_voltage,
+            );
+        compare(temperature.0, expected.0, 1e-9);
+
+        // Both junctions at 0ºC
+        let temperature: Celsius = thermocouple
+            .sense_temperature_from_raw(
+                Millivolts(0.0),
+                Millivolts(0.0),
+            );
+        compare(temperature.0, 0.0, 0.05);
+    }
+
+    #[cfg(not(feature = "extrapolate"))]
+    #[test]
+    /// Test an out of range cold junction voltage follows the range
+    /// mode
+    fn sense_temperature_from_raw_out_of_range() {
+        let thermocouple = JType::new();
+        let open_circuit = Millivolts(100.0);
+
+        let result: Result<Celsius, _> = thermocouple
+            .try_sense_temperature_from_raw(
+                Millivolts(4.0),
+                open_circuit,
+            );
+        match result {
+            Err(ThermocoupleError::Range(err)) => {
+                assert_eq!(err.value, open_circuit)
+            }
+            _ => panic!("Expected a range error"),
+        }
+        let result: Result<Celsius, _> = thermocouple
+            .try_sense_temperature_from_raw(
+                Millivolts(4.0),
+                Millivolts(1.277),
+            );
+        assert!(result.is_ok());
+
+        let _: Celsius = thermocouple
+            .with_clamping()
+            .sense_temperature_from_raw(Millivolts(4.0), open_circuit);
+    }
+
+    #[test]
+    /// Test an in range temperature gives the same result as
+    /// `sense_voltage`
+    fn try_sense_voltage_in_range() {
+        let thermocouple = JType::new();
+        let voltage = thermocouple.sense_voltage(Celsius(100.0));
+
+        assert_eq!(
+            thermocouple.try_sense_voltage(Celsius(100.0)),
+            Ok(voltage)
+        );
+    }
+
+    #[test]
+    /// Test an implementation outside this crate only needs to provide
+    /// the infallible methods
+    fn try_sense_default() {
+        struct Linear;
+
+        impl ThermocoupleCore<Celsius> for Linear {
+            fn sense_temperature(
+                &self,
+                voltage: Millivolts,
+            ) -> Celsius {
+                Celsius(voltage.0 * 25.0)
+            }
+            fn sense_voltage(
+                &self,
+                temperature: Celsius,
+            ) -> Millivolts {
+                Millivolts(temperature.0 / 25.0)
+            }
+        }
+
+        assert_eq!(
+            Linear.try_sense_temperature(Millivolts(2.0)),
+            Ok(Celsius(50.0))
+        );
+        assert_eq!(
+            Linear.try_sense_voltage(Celsius(50.0)),
+            Ok(Millivolts(2.0))
+        );
+    }
+
+    #[test]
+    /// Test an in range voltage gives the same result as
+    /// `sense_temperature`
+    fn try_sense_temperature_in_range() {
+        let thermocouple = JType::new();
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+
+        assert_eq!(
+            thermocouple.try_sense_temperature(Millivolts(1.1)),
+            Ok(temperature)
+        );
+    }
 }
@@ -0,0 +1,85 @@
+//! Lookup tables for evaluating E(T) and T by linear interpolation.
+//!
+//! The tables are computed at compile time from the reference
+//! functions, with an entry every 10ºC across the full temperature
+//! range.
+
+use crate::{Celsius, Millivolts, FP};
+
+/// Spacing between entries in a lookup table, in Celsius
+pub(crate) const STEP: FP = 10.0;
+
+/// Number of entries in a lookup table spanning `range`, including
+/// both ends
+pub(crate) const fn table_len(range: (Celsius, Celsius)) -> usize {
+    let (min, max) = range;
+    let span = max.0 - min.0;
+    let steps = (span / STEP) as usize;
+
+    match (steps as FP) * STEP < span {
+        true => steps + 2, // Shorter final step
+        false => steps + 1,
+    }
+}
+
+/// Lookup table of E(T) at increasing temperatures
+#[derive(Debug)]
+pub(crate) struct Table<const N: usize> {
+    /// Temperatures, in Celsius
+    pub(crate) t: [FP; N],
+    /// E(T) at each temperature, in millivolts
+    pub(crate) e: [FP; N],
+}
+
+/// Build a lookup table spanning `$range` with `$len` entries, where
+/// `$e` evaluates E(T) in millivolts for temperature `$t` in Celsius
+macro_rules! lookup_table {
+    ($range:expr, $len:expr, |$t:ident| $e:expr) => {{
+        let (min, max) = $range;
+        let mut table = crate::lut::Table {
+            t: [0.0; $len],
+            e: [0.0; $len],
+        };
+
+        let mut i = 0;
+        while i < $len {
+            let $t = match i + 1 < $len {
+                true => min.0 + (i as FP) * crate::lut::STEP,
+                false => max.0,
+            };
+            table.t[i] = $t;
+            table.e[i] = $e;
+            i += 1;
+        }
+
+        table
+    }};
+}
+
+/// Index of the interval `[xs[i], xs[i + 1]]` containing `x`. Values
+/// beyond either end use the first or last interval.
+fn interval(xs: &[FP], x: FP) -> usize {
+    xs[1..xs.len() - 1].partition_point(|&x_i| x_i <= x)
+}
+
+/// Linearly interpolate between `(xs[i], ys[i])` and `(xs[i + 1], ys[i +
+/// 1])`
+fn interpolate(xs: &[FP], ys: &[FP], x: FP) -> FP {
+    let i = interval(xs, x);
+
+    ys[i] + (x - xs[i]) * (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i])
+}
+
+impl<const N: usize> Table<N> {
+    /// Interpolate E(T), where T is in Celsius and E(T) is in
+    /// millivolts.
+    pub(crate) fn e(&self, t: Celsius) -> Millivolts {
+        Millivolts(interpolate(&self.t, &self.e, t.0))
+    }
+    /// Interpolate T given E(T), where T is in Celsius and E(T) is in
+    /// millivolts. E(T) must be increasing over the table, or at least
+    /// from the highest entry below `e`.
+    pub(crate) fn t(&self, e: Millivolts) -> Celsius {
+        Celsius(interpolate(&self.e, &self.t, e.0))
+    }
+}
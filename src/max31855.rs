@@ -0,0 +1,110 @@
+//! Correction of temperatures reported by MAX31855 thermocouple
+//! converters.
+//!
+//! The MAX31855 reports a temperature that assumes a constant Seebeck
+//! coefficient for each type, which is inaccurate away from its
+//! linearization point. It also reports the cold-junction temperature,
+//! so the measured thermoelectric potential can be recovered and
+//! converted with the reference functions instead.
+
+use crate::{
+    Celsius, Millivolts, Thermocouple, ThermocoupleCore,
+    ThermocoupleType, FP,
+};
+
+/// Seebeck coefficient assumed by the MAX31855 variant for each type, in
+/// microvolts per degree Celsius. There is no Type B variant.
+fn sensitivity(kind: ThermocoupleType) -> Option<FP> {
+    match kind {
+        ThermocoupleType::B => None,
+        ThermocoupleType::E => Some(76.373),
+        ThermocoupleType::J => Some(57.953),
+        #[cfg(any(feature = "k-type", feature = "no-libm"))]
+        ThermocoupleType::K => Some(41.276),
+        ThermocoupleType::N => Some(36.256),
+        ThermocoupleType::R => Some(10.506),
+        ThermocoupleType::S => Some(9.587),
+        ThermocoupleType::T => Some(52.18),
+    }
+}
+
+/// Returns the thermocouple temperature for a MAX31855 `reported`
+/// temperature, where the chip also reported its cold-junction
+/// temperature as `cold_junction`. The measured thermoelectric
+/// potential is recovered from the chip's linear model and converted
+/// with the reference functions for `kind`.
+///
+/// Returns `None` for a Type B thermocouple, which the MAX31855 does not
+/// support, or when the recovered potential is outside the range of the
+/// inverse function.
+pub fn delinearize_max31855(
+    reported: Celsius,
+    cold_junction: Celsius,
+    kind: ThermocoupleType,
+) -> Option<Celsius> {
+    let sensitivity = sensitivity(kind)? / 1000.0; // mV/ºC
+    let voltage =
+        Millivolts(sensitivity * (reported - cold_junction).0);
+
+    let thermocouple = Thermocouple::from(kind)
+        .with_reference_temperature(cold_junction);
+
+    thermocouple.try_sense_temperature(voltage).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    /// Test correcting a Type K reading with the chip's linear model
+    fn k_type() {
+        use crate::tests::compare;
+
+        // NIST ITS-90: E(700ºC) = 29.129mV, E(25ºC) = 1.000mV
+        let cold_junction = Celsius(25.0);
+        let voltage = Millivolts(29.129 - 1.000);
+
+        // As reported by the chip
+        let reported = cold_junction + Celsius(voltage.0 / 0.041276);
+        assert!(reported > Celsius(705.0));
+
+        let corrected = delinearize_max31855(
+            reported,
+            cold_junction,
+            ThermocoupleType::K,
+        );
+
+        #[cfg(not(feature = "reduced-order"))]
+        const TOLERANCE: FP = 0.05; // ±0.05ºC tolerance
+        #[cfg(feature = "reduced-order")]
+        const TOLERANCE: FP = 1.0; // ±1ºC tolerance
+        compare(corrected.unwrap().0, 700.0, TOLERANCE);
+    }
+
+    #[test]
+    #[cfg(all(
+        any(feature = "k-type", feature = "no-libm"),
+        not(feature = "extrapolate")
+    ))]
+    /// Test a reading beyond the range of the inverse function
+    fn k_type_out_of_range() {
+        let corrected = delinearize_max31855(
+            Celsius(2000.0),
+            Celsius(25.0),
+            ThermocoupleType::K,
+        );
+        assert!(corrected.is_none());
+    }
+
+    #[test]
+    fn b_type_unsupported() {
+        let corrected = delinearize_max31855(
+            Celsius(700.0),
+            Celsius(25.0),
+            ThermocoupleType::B,
+        );
+        assert!(corrected.is_none());
+    }
+}
@@ -1,6 +1,9 @@
 //! N-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const N_TYPE_E_BELOW_0: [FP; 9] = [
     0.000000000000E+00,
     0.261591059620E-01,
@@ -12,6 +15,7 @@ const N_TYPE_E_BELOW_0: [FP; 9] = [
     -0.760893007910E-16,
     -0.934196678350E-19,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const N_TYPE_E_ABOVE_0: [FP; 11] = [
     0.000000000000E+00,
     0.259293946010E-01,
@@ -26,6 +30,7 @@ const N_TYPE_E_ABOVE_0: [FP; 11] = [
     -0.306821961510E-28,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const N_TYPE_T0: [FP; 10] = [
     0.0000000E+00,
     3.8436847E+01,
@@ -38,6 +43,7 @@ const N_TYPE_T0: [FP; 10] = [
     1.1582665E-01,
     7.3138868E-03,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const N_TYPE_T1: [FP; 10] = [
     0.00000E+00,
     3.86896E+01,
@@ -50,6 +56,7 @@ const N_TYPE_T1: [FP; 10] = [
     0.00000E+00,
     0.00000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const N_TYPE_T2: [FP; 10] = [
     1.972485E+01,
     3.300943E+01,
@@ -63,82 +70,274 @@ const N_TYPE_T2: [FP; 10] = [
     0.000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const N_TYPE_E_BELOW_0: [FP; 4] = [
+    0.1052328510E-01,
+    0.2698256309E-01,
+    0.2020290577E-04,
+    -0.7409223922E-07,
+];
+#[cfg(feature = "reduced-order")]
+const N_TYPE_E_ABOVE_0: [FP; 5] = [
+    -0.3151980773E-02,
+    0.2581630180E-01,
+    0.2177071222E-04,
+    -0.1444049299E-07,
+    0.3116238701E-11,
+];
+#[cfg(feature = "reduced-order")]
+const N_TYPE_T0: [FP; 6] = [
+    0.2770583568E+00,
+    0.4078193885E+02,
+    0.4805437639E+01,
+    0.4585079917E+01,
+    0.1478330183E+01,
+    0.1947632479E+00,
+];
+#[cfg(feature = "reduced-order")]
+const N_TYPE_T1: [FP; 6] = [
+    -0.3304051530E-01,
+    0.3875117855E+02,
+    -0.1124258477E+01,
+    0.5950342311E-01,
+    -0.1857321629E-02,
+    0.2503369898E-04,
+];
+#[cfg(feature = "reduced-order")]
+const N_TYPE_T2: [FP; 6] = [
+    0.2160270920E+02,
+    0.3270167617E+02,
+    -0.3717099235E+00,
+    0.9229613921E-02,
+    -0.1177259942E-03,
+    0.7174497738E-06,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &N_TYPE_E_BELOW_0,
+    &N_TYPE_E_ABOVE_0,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &N_TYPE_T0,
+    &N_TYPE_T1,
+    &N_TYPE_T2,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    N_TYPE_E_BELOW_0[0] == 0.0
+        && N_TYPE_E_ABOVE_0[0] == 0.0
+        && N_TYPE_T0[0] == 0.0
+        && N_TYPE_T1[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0], E_STEP_MAX));
+
+    let [v0, v1] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0, v1.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-270.0), Celsius(1300.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-4.345), Millivolts(47.513));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-3.990), Millivolts(47.513));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(0.0)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 2] =
+    [Millivolts(0.0), Millivolts(20.613)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(-100.0), Millivolts(-2.407)),
+    (Celsius(300.0), Millivolts(9.341)),
+    (Celsius(1000.0), Millivolts(36.256)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a N-Type thermocouple in the range -270ºC to
 /// 1300ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -270.0);
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1300.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
 
-    let e = match t > 0.0 {
+    e_unchecked(t)
+}
+
+/// Evaluate E(T) for a N-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // -270ºC -> 0ºC
-            const C: [FP; 9] = N_TYPE_E_BELOW_0;
+            let c = N_TYPE_E_BELOW_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
         _ => {
             // 0ºC -> 1300ºC
-            const C: [FP; 11] = N_TYPE_E_ABOVE_0;
+            let c = N_TYPE_E_ABOVE_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
-                + C[10] * t * t * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a N-Type thermocouple in
+/// the range -270ºC to 1300ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// N-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(N_TYPE_E_BELOW_0, t),
+        _ => polyval_with_derivative(N_TYPE_E_ABOVE_0, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a N-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (-40.0..=1000.0).contains(&t) => {
+            deviation(t, 1.5, 0.004)
+        }
+        ToleranceClass::Two if (-40.0..=1200.0).contains(&t) => {
+            deviation(t, 2.5, 0.0075)
+        }
+        ToleranceClass::Three if (-200.0..=40.0).contains(&t) => {
+            deviation(t, 2.5, 0.015)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// Range of temperatures over which NX extension wire is defined by
+/// IEC 60584-3
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> =
+    Some((Celsius(-25.0), Celsius(200.0)));
+
+/// Evaluate the permissible deviation for NX extension wire of the given
+/// IEC 60584-3 tolerance class, within its temperature range. Returns
+/// `None` if the class is not defined.
+pub fn extension_tolerance(class: ToleranceClass) -> Option<Celsius> {
+    match class {
+        ToleranceClass::One => Some(Celsius(1.5)),
+        ToleranceClass::Two => Some(Celsius(2.5)),
+        _ => None,
+    }
+}
+
 /// Evaluate T for a N-Type thermocouple given E(T) in the range
 /// -3.990mV to 47.513mV, where T is in Celsius and E(T) is in millivolts.
+///
+/// NIST does not define an inverse function below -200ºC, so E(T) from
+/// -270ºC to -200ºC is out of range even though E(T) is defined there.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -3.990 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 47.513 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match (e < 0.0, e < 20.613) {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a N-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a N-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0, b1] = INVERSE_BREAKPOINTS;
+    let c = match (e < b0.0, e < b1.0) {
         (true, _) => N_TYPE_T0,
         (false, true) => N_TYPE_T1,
         (false, false) => N_TYPE_T2,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e
-        + c[9] * e * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -148,8 +347,12 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 0.6;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
-        "../nist/type_n.tab.rs", -270, 1300, |t| t >= -200 && t <= 1300
+        "../nist/type_n.tab.rs", -270, 1300, |t| (-200..=1300).contains(&t)
     }
 }
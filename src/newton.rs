@@ -0,0 +1,35 @@
+//! Refinement of the inverse functions by Newton-Raphson iteration.
+//!
+//! The NIST inverse polynomials only approximate the inverse of E(T),
+//! to within about 0.05ºC. Iterating on E(T) itself removes this
+//! error.
+
+use crate::{Celsius, Millivolts, FP};
+
+/// Number of Newton-Raphson steps. The inverse polynomials are a good
+/// enough starting point that two steps reach the limit of the
+/// floating point precision.
+const STEPS: usize = 2;
+
+/// Refines `t`, an estimate of the temperature at which E(T) equals
+/// `target`. `e_and_seebeck` evaluates E(T) together with dE/dT in
+/// microvolts per degree Celsius.
+pub(crate) fn refine(
+    target: Millivolts,
+    t: Celsius,
+    e_and_seebeck: impl Fn(Celsius) -> (Millivolts, FP),
+) -> Celsius {
+    let mut t = t;
+
+    for _ in 0..STEPS {
+        let (e, seebeck) = e_and_seebeck(t);
+        let de_dt = seebeck / 1000.0; // mV/ºC
+        if de_dt == 0.0 {
+            break;
+        }
+
+        t -= Celsius((e - target).0 / de_dt);
+    }
+
+    t
+}
@@ -0,0 +1,220 @@
+//! Polynomial evaluation.
+
+use crate::FP;
+
+/// Evaluate the polynomial with coefficients `c` at `x`, where `c[i]` is
+/// the coefficient of `x^i`.
+///
+/// Uses Horner's method, which needs one multiply and one add per
+/// coefficient. With the `estrin` feature, polynomials with 10 or more
+/// coefficients are evaluated with Estrin's scheme instead.
+pub(crate) const fn polyval<const N: usize>(c: [FP; N], x: FP) -> FP {
+    if cfg!(feature = "estrin") && N >= 10 {
+        return polyval_estrin(c, x);
+    }
+
+    polyval_horner(c, x)
+}
+
+/// Evaluate the polynomial with coefficients `c` at `x` by Horner's
+/// method, where `c[i]` is the coefficient of `x^i`.
+const fn polyval_horner<const N: usize>(c: [FP; N], x: FP) -> FP {
+    let mut acc = 0.0;
+    let mut i = N;
+
+    while i > 0 {
+        i -= 1;
+        acc = acc * x + c[i];
+    }
+
+    acc
+}
+
+/// Evaluate the polynomial with coefficients `c` at `x`, where `c[i]` is
+/// the coefficient of `x^i`.
+///
+/// Uses Estrin's scheme within blocks of four coefficients, where each
+/// block is `(c[4k] + c[4k + 1] x) + (c[4k + 2] + c[4k + 3] x) x²`. The
+/// blocks are independent, and are combined by Horner's method in `x⁴`.
+/// This has a dependency chain about a quarter the length of Horner's
+/// method, at the cost of a few more multiplies.
+///
+/// If `x⁴` overflows, adding infinite terms of opposite sign would give
+/// NaN. Horner's method is used instead, as its partial sums only
+/// overflow once the result itself does.
+pub(crate) const fn polyval_estrin<const N: usize>(
+    c: [FP; N],
+    x: FP,
+) -> FP {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+
+    if !x4.is_finite() {
+        return polyval_horner(c, x);
+    }
+
+    let mut acc = 0.0;
+    let mut k = N.div_ceil(4);
+
+    while k > 0 {
+        k -= 1;
+        let i = 4 * k;
+        let c0 = if i < N { c[i] } else { 0.0 };
+        let c1 = if i + 1 < N { c[i + 1] } else { 0.0 };
+        let c2 = if i + 2 < N { c[i + 2] } else { 0.0 };
+        let c3 = if i + 3 < N { c[i + 3] } else { 0.0 };
+
+        acc = acc * x4 + ((c0 + c1 * x) + (c2 + c3 * x) * x2);
+    }
+
+    acc
+}
+
+/// Evaluate the first derivative of the polynomial with coefficients
+/// `c` at `x`, where `c[i]` is the coefficient of `x^i`.
+pub(crate) fn polyval_derivative<const N: usize>(
+    c: [FP; N],
+    x: FP,
+) -> FP {
+    c.iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(0.0, |acc, (i, &c_i)| acc * x + (i as FP) * c_i)
+}
+
+/// Evaluate the polynomial with coefficients `c` and its first
+/// derivative at `x` together, where `c[i]` is the coefficient of
+/// `x^i`. Returns `(value, derivative)`.
+///
+/// Uses Horner's method for both in a single pass, where each step of
+/// the derivative uses the partial value from the step before.
+pub(crate) fn polyval_with_derivative<const N: usize>(
+    c: [FP; N],
+    x: FP,
+) -> (FP, FP) {
+    c.iter()
+        .rev()
+        .fold((0.0, 0.0), |(p, dp), &c_i| (p * x + c_i, dp * x + p))
+}
+
+/// Largest step in E(T) between adjacent coefficient sets, in
+/// millivolts
+#[cfg(not(feature = "reduced-order"))]
+pub(crate) const E_STEP_MAX: FP = 0.0005;
+/// Largest step in an inverse function between adjacent coefficient
+/// sets, in degrees Celsius. Each NIST inverse set is only accurate to
+/// about ±0.05ºC.
+#[cfg(not(feature = "reduced-order"))]
+pub(crate) const T_STEP_MAX: FP = 0.1;
+
+/// Returns true if each pair of adjacent polynomials in `c` agree to
+/// within `tolerance` at the breakpoint between them, where
+/// `breakpoints[i]` lies between `c[i]` and `c[i + 1]`. For checking
+/// piecewise coefficients at compile time.
+#[cfg(not(feature = "reduced-order"))]
+pub(crate) const fn is_continuous(
+    c: &[&[FP]],
+    breakpoints: &[FP],
+    tolerance: FP,
+) -> bool {
+    let mut i = 0;
+
+    while i < breakpoints.len() {
+        let x = breakpoints[i];
+        let step = polyval_slice(c[i + 1], x) - polyval_slice(c[i], x);
+
+        if !(step <= tolerance && step >= -tolerance) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Evaluate the polynomial with coefficients `c` at `x` by Horner's
+/// method, where the number of coefficients is only known at run time.
+#[cfg(not(feature = "reduced-order"))]
+const fn polyval_slice(c: &[FP], x: FP) -> FP {
+    let mut acc = 0.0;
+    let mut i = c.len();
+
+    while i > 0 {
+        i -= 1;
+        acc = acc * x + c[i];
+    }
+
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        polyval, polyval_derivative, polyval_estrin,
+        polyval_with_derivative,
+    };
+    use crate::tests::compare;
+    use crate::FP;
+
+    #[test]
+    fn test_polyval() {
+        // 1 + 2x + 3x²
+        assert_eq!(polyval([1.0, 2.0, 3.0], 2.0), 17.0);
+        assert_eq!(polyval([1.0, 2.0, 3.0], 0.0), 1.0);
+        assert_eq!(polyval([], 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_polyval_estrin() {
+        // 1 + 2x + 3x²
+        assert_eq!(polyval_estrin([1.0, 2.0, 3.0], 2.0), 17.0);
+        assert_eq!(polyval_estrin([1.0, 2.0, 3.0], 0.0), 1.0);
+        assert_eq!(polyval_estrin([], 2.0), 0.0);
+        assert_eq!(polyval_estrin([5.0], 2.0), 5.0);
+
+        // Odd and even lengths agree with Horner's method
+        let c = [0.5, -1.0, 0.25, 2.0, -0.125, 1.5, 0.75, -0.5, 0.1];
+        for x in &[-2.0, -0.3, 0.0, 0.7, 1.9] {
+            compare(polyval_estrin(c, *x), polyval(c, *x), 1e-4);
+
+            let c = [c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]];
+            compare(polyval_estrin(c, *x), polyval(c, *x), 1e-4);
+        }
+
+        // Overflows to infinity rather than NaN
+        let c = [1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0];
+        assert_eq!(polyval_estrin(c, FP::MAX), FP::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_polyval_derivative() {
+        // d/dx (1 + 2x + 3x²) = 2 + 6x
+        assert_eq!(polyval_derivative([1.0, 2.0, 3.0], 2.0), 14.0);
+        assert_eq!(polyval_derivative([1.0, 2.0, 3.0], 0.0), 2.0);
+        assert_eq!(polyval_derivative([1.0], 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_polyval_with_derivative() {
+        // 1 + 2x + 3x², 2 + 6x
+        assert_eq!(
+            polyval_with_derivative([1.0, 2.0, 3.0], 2.0),
+            (17.0, 14.0)
+        );
+        assert_eq!(polyval_with_derivative([1.0], 2.0), (1.0, 0.0));
+        assert_eq!(polyval_with_derivative([], 2.0), (0.0, 0.0));
+
+        // Agrees with a central finite difference
+        const H: FP = 1e-3;
+        let c = [0.5, -1.0, 0.25, 2.0, -0.125, 1.5, 0.75, -0.5, 0.1];
+        for x in &[-2.0, -0.3, 0.0, 0.7, 1.9] {
+            let (p, dp) = polyval_with_derivative(c, *x);
+            let finite =
+                (polyval(c, x + H) - polyval(c, x - H)) / (2.0 * H);
+
+            compare(p, polyval(c, *x), 1e-4);
+            compare(finite / dp, 1.0, 1e-3);
+        }
+    }
+}
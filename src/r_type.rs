@@ -1,6 +1,9 @@
 //! R-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_E_BELOW_1064_18: [FP; 10] = [
     0.000000000000E+00,
     0.528961729765E-02,
@@ -13,6 +16,7 @@ const R_TYPE_E_BELOW_1064_18: [FP; 10] = [
     0.157716482367E-22,
     -0.281038625251E-26,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 6] = [
     0.295157925316E+01,
     -0.252061251332E-02,
@@ -21,6 +25,7 @@ const R_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 6] = [
     0.205305291024E-11,
     -0.293359668173E-15,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_E_ABOVE_1664_5: [FP; 5] = [
     0.152232118209E+03,
     -0.268819888545E+00,
@@ -29,6 +34,7 @@ const R_TYPE_E_ABOVE_1664_5: [FP; 5] = [
     -0.934633971046E-14,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_T0: [FP; 11] = [
     0.0000000E+00,
     1.8891380E+02,
@@ -42,6 +48,7 @@ const R_TYPE_T0: [FP; 11] = [
     3.1353611E+01,
     -3.3187769E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_T1: [FP; 11] = [
     1.334584505E+01,
     1.472644573E+02,
@@ -55,6 +62,7 @@ const R_TYPE_T1: [FP; 11] = [
     6.481976217E-08,
     0.000000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_T2: [FP; 11] = [
     -8.199599416E+01,
     1.553962042E+02,
@@ -68,6 +76,7 @@ const R_TYPE_T2: [FP; 11] = [
     0.000000000E+00,
     0.000000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const R_TYPE_T3: [FP; 11] = [
     3.406177836E+04,
     -7.023729171E+03,
@@ -82,72 +91,283 @@ const R_TYPE_T3: [FP; 11] = [
     0.000000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const R_TYPE_E_BELOW_1064_18: [FP; 6] = [
+    0.1675542491E-02,
+    0.5290231028E-02,
+    0.1316492647E-04,
+    -0.1759026684E-07,
+    0.1385235129E-10,
+    -0.4210245896E-14,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 3] = [
+    -0.3241757360E+01,
+    0.1353346451E-01,
+    0.1685059985E-06,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_E_ABOVE_1664_5: [FP; 2] = [
+    -0.2186494527E+01,
+    0.1317745942E-01,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_T0: [FP; 6] = [
+    -0.1416674636E+00,
+    0.1915367451E+03,
+    -0.9256276171E+02,
+    0.7392125968E+02,
+    -0.3399050971E+02,
+    0.6222593622E+01,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_T1: [FP; 6] = [
+    0.3125388255E+02,
+    0.1237685760E+03,
+    -0.6018170780E+01,
+    0.5167286493E+00,
+    -0.2923258995E-01,
+    0.7006464598E-03,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_T2: [FP; 6] = [
+    -0.7654190546E+02,
+    0.1536501288E+03,
+    -0.8120209694E+01,
+    0.4139308998E+00,
+    -0.1147653275E-01,
+    0.1437576137E-03,
+];
+#[cfg(feature = "reduced-order")]
+const R_TYPE_T3: [FP; 6] = [
+    -0.3012458792E+06,
+    0.7510367206E+05,
+    -0.7486858672E+04,
+    0.3744718036E+03,
+    -0.9390218426E+01,
+    0.9445626459E-01,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &R_TYPE_E_BELOW_1064_18,
+    &R_TYPE_E_ABOVE_1064_18_BELOW_1664_5,
+    &R_TYPE_E_ABOVE_1664_5,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &R_TYPE_T0,
+    &R_TYPE_T1,
+    &R_TYPE_T2,
+    &R_TYPE_T3,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    R_TYPE_E_BELOW_1064_18[0] == 0.0
+        && R_TYPE_T0[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0, b1.0], E_STEP_MAX));
+
+    let [v0, v1, v2] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(
+        T_COEFFICIENTS,
+        &[v0.0, v1.0, v2.0],
+        T_STEP_MAX
+    ));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-50.0), Celsius(1768.1));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-0.226), Millivolts(21.103));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-0.226), Millivolts(21.103));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 2] =
+    [Celsius(1064.18), Celsius(1664.5)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 3] =
+    [Millivolts(1.923), Millivolts(13.228), Millivolts(19.739)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(100.0), Millivolts(0.647)),
+    (Celsius(700.0), Millivolts(6.743)),
+    (Celsius(1400.0), Millivolts(16.040)),
+    (Celsius(1700.0), Millivolts(20.222)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a R-Type thermocouple in the range -50ºC to
 /// 1768.1ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -50.0);
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1768.1);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
+
+    e_unchecked(t)
+}
 
-    let e = match (t > 1064.18, t > 1664.5) {
+/// Evaluate E(T) for a R-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    let e = match (t > b0.0, t > b1.0) {
         (false, _) => {
             // -50ºC -> 1064.18ºC
-            const C: [FP; 10] = R_TYPE_E_BELOW_1064_18;
+            let c = R_TYPE_E_BELOW_1064_18;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
         (true, false) => {
             // 1064.18ºC -> 1664.5ºC
-            const C: [FP; 6] = R_TYPE_E_ABOVE_1064_18_BELOW_1664_5;
+            let c = R_TYPE_E_ABOVE_1064_18_BELOW_1664_5;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
+            polyval(c, t)
         }
         (true, true) => {
             // 1664.5ºC -> 1768.1ºC
-            const C: [FP; 5] = R_TYPE_E_ABOVE_1664_5;
+            let c = R_TYPE_E_ABOVE_1664_5;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a R-Type thermocouple in
+/// the range -50ºC to 1768.1ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// R-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match (t > b0.0, t > b1.0) {
+        (false, _) => polyval_with_derivative(R_TYPE_E_BELOW_1064_18, t),
+        (true, false) => polyval_with_derivative(R_TYPE_E_ABOVE_1064_18_BELOW_1664_5, t),
+        (true, true) => polyval_with_derivative(R_TYPE_E_ABOVE_1664_5, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a R-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (0.0..=1100.0).contains(&t) => 1.0,
+        ToleranceClass::One if (1100.0..=1600.0).contains(&t) => {
+            1.0 + 0.003 * (t - 1100.0)
+        }
+        ToleranceClass::Two if (0.0..=1600.0).contains(&t) => {
+            deviation(t, 1.5, 0.0025)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// IEC 60584-3 defines only compensating cable for R-Type
+/// thermocouples, not extension wire
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> = None;
+
+/// Always returns `None`, as IEC 60584-3 defines only compensating
+/// cable for R-Type thermocouples, not extension wire.
+pub fn extension_tolerance(_class: ToleranceClass) -> Option<Celsius> {
+    None
+}
+
 /// Evaluate T for a R-Type thermocouple given E(T) in the range
 /// -0.226mV to 21.103mV, where T is in Celsius and E(T) is in millivolts.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -0.226 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 21.103 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match (e < 1.923, e < 13.228, e < 19.739) {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a R-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a R-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0, b1, b2] = INVERSE_BREAKPOINTS;
+    let c = match (e < b0.0, e < b1.0, e < b2.0) {
         (true, _, _) => R_TYPE_T0,
         (false, true, _) => R_TYPE_T1,
         (false, false, true) => R_TYPE_T2,
@@ -155,17 +375,7 @@ pub fn t(e: Millivolts) -> Celsius {
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e
-        + c[9] * e * e * e * e * e * e * e * e * e
-        + c[10] * e * e * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -175,6 +385,10 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 1.0;
+
     nist_its_90! {
         // NIST inverse function is defined over full range
         "../nist/type_r.tab.rs", -50, 1768, |_| true
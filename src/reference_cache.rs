@@ -0,0 +1,44 @@
+//! Caching of the reference junction potential.
+//!
+//! When the reference junction temperature is measured as often as the
+//! thermoelectric potential, evaluating E(T) for it on every sample is
+//! wasteful if it changes slowly. A [`ReferenceCache`] only evaluates
+//! E(T) again once the reference junction temperature has moved by
+//! more than a threshold.
+
+use crate::{Celsius, Millivolts, ThermocoupleCore};
+
+/// Thermocouple with a reference junction temperature that is updated
+/// only when it changes by more than a threshold. Constructed with
+/// `with_reference_cache` on a thermocouple type.
+#[derive(Clone, Copy, Debug)]
+pub struct ReferenceCache<T> {
+    pub(crate) thermocouple: T,
+    pub(crate) last_reference: Celsius,
+    pub(crate) threshold: Celsius,
+}
+
+impl<T> ReferenceCache<T> {
+    /// Returns the reference junction temperature currently in use
+    pub fn last_reference(&self) -> Celsius {
+        self.last_reference
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, with the cached reference junction potential.
+    pub fn sense_temperature<W>(&self, voltage: Millivolts) -> W
+    where
+        T: ThermocoupleCore<W>,
+    {
+        self.thermocouple.sense_temperature(voltage)
+    }
+    /// Returns the thermocouple, with the reference junction at the
+    /// temperature currently in use
+    pub fn into_inner(self) -> T {
+        self.thermocouple
+    }
+    /// Returns true if `reference` differs from the reference junction
+    /// temperature in use by more than the threshold
+    pub(crate) fn is_stale(&self, reference: Celsius) -> bool {
+        !reference.approx_eq(&self.last_reference, self.threshold.0)
+    }
+}
@@ -0,0 +1,44 @@
+//! Rounding of values to a display step.
+
+use crate::FP;
+
+/// Handling of values that lie exactly halfway between two multiples
+/// of the step being rounded to
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RoundingMode {
+    /// Round halves away from zero
+    HalfUp,
+    /// Round halves to the even multiple, also known as banker's
+    /// rounding
+    HalfEven,
+    /// Truncate toward zero
+    TowardZero,
+}
+
+/// Rounds `x` to a multiple of `step`. A `step` of zero returns `x`
+/// unchanged.
+pub(crate) fn round(x: FP, step: FP, mode: RoundingMode) -> FP {
+    if step == 0.0 {
+        return x;
+    }
+
+    let q = x / step;
+    let fract = q % 1.0; // Same sign as q
+    let trunc = q - fract;
+    let away = match fract < 0.0 {
+        true => trunc - 1.0,
+        false => trunc + 1.0,
+    };
+    let half = fract == 0.5 || fract == -0.5;
+    let beyond_half = !(-0.5..=0.5).contains(&fract);
+
+    let n = match mode {
+        RoundingMode::TowardZero => trunc,
+        RoundingMode::HalfUp if half || beyond_half => away,
+        RoundingMode::HalfEven if beyond_half => away,
+        RoundingMode::HalfEven if half && trunc % 2.0 != 0.0 => away,
+        _ => trunc,
+    };
+
+    n * step
+}
@@ -0,0 +1,166 @@
+//! Platinum resistance thermometers (RTDs).
+//!
+//! Uses the Callendar-Van Dusen equation with the IEC 60751
+//! coefficients for industrial platinum resistance thermometers.
+
+use crate::polyval::{polyval, polyval_derivative};
+use crate::{Celsius, Ohms, FP};
+
+/// Callendar-Van Dusen coefficient A
+const A: FP = 3.9083E-03;
+/// Callendar-Van Dusen coefficient B
+const B: FP = -5.775E-07;
+/// Callendar-Van Dusen coefficient C, only used below 0ºC
+const C: FP = -4.183E-12;
+
+/// R(T) / R0 at or above 0ºC
+const RTD_ABOVE_0: [FP; 3] = [1.0, A, B];
+/// R(T) / R0 below 0ºC
+const RTD_BELOW_0: [FP; 5] = [1.0, A, B, -100.0 * C, C];
+
+/// Range of temperatures over which R(T) is defined
+#[cfg(not(any(feature = "extrapolate")))]
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-200.0), Celsius(850.0));
+
+/// Evaluate R(T) / R0 for a platinum RTD in the range -200ºC to 850ºC,
+/// where T is in Celsius.
+fn r(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    let t = t.0;
+
+    match t < 0.0 {
+        true => polyval(RTD_BELOW_0, t),
+        false => polyval(RTD_ABOVE_0, t),
+    }
+}
+
+/// Evaluate T for a platinum RTD given R(T) / R0, where T is in
+/// Celsius.
+fn t(ratio: FP) -> Celsius {
+    // Newton's method, starting from the linear approximation
+    let mut t = (ratio - 1.0) / A;
+
+    for _ in 0..8 {
+        let (c, dc) = match t < 0.0 {
+            true => (
+                polyval(RTD_BELOW_0, t),
+                polyval_derivative(RTD_BELOW_0, t),
+            ),
+            false => (
+                polyval(RTD_ABOVE_0, t),
+                polyval_derivative(RTD_ABOVE_0, t),
+            ),
+        };
+        t -= (c - ratio) / dc;
+    }
+
+    let t = Celsius(t);
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    t
+}
+
+macro_rules! rtd {
+    ($($Type:ident: $r0:expr, $doc:expr;)*) => {
+        $(
+            #[doc=$doc]
+            #[derive(Clone, Copy, Debug)]
+            pub struct $Type;
+
+            impl $Type {
+                /// Resistance at 0ºC
+                pub const R0: Ohms = Ohms($r0);
+
+                /// New RTD instance
+                pub const fn new() -> $Type {
+                    $Type
+                }
+                /// Returns the RTD temperature for a given
+                /// resistance.
+                pub fn resistance_to_temperature(
+                    &self,
+                    resistance: Ohms,
+                ) -> Celsius {
+                    t(resistance.0 / Self::R0.0)
+                }
+                /// Returns the RTD resistance for a given
+                /// temperature.
+                pub fn temperature_to_resistance<T>(
+                    &self,
+                    temperature: T,
+                ) -> Ohms
+                where
+                    T: Into<Celsius>,
+                {
+                    Ohms(Self::R0.0 * r(temperature.into()))
+                }
+            }
+            impl Default for $Type {
+                fn default() -> Self {
+                    $Type::new()
+                }
+            }
+        )*
+    };
+}
+
+rtd! {
+    Pt100: 100.0, "Platinum RTD, 100Ω at 0ºC";
+    Pt1000: 1000.0, "Platinum RTD, 1000Ω at 0ºC";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pt100, Pt1000};
+    use crate::tests::compare;
+    use crate::{Celsius, Ohms, FP};
+
+    #[test]
+    /// Test against IEC 60751 table values
+    fn pt100() {
+        let rtd = Pt100::new();
+
+        compare(
+            rtd.temperature_to_resistance(Celsius(0.0)).0,
+            100.0,
+            1e-4,
+        );
+        compare(
+            rtd.temperature_to_resistance(Celsius(100.0)).0,
+            138.5055,
+            1e-3,
+        );
+        compare(
+            rtd.temperature_to_resistance(Celsius(-100.0)).0,
+            60.2558,
+            1e-3,
+        );
+        compare(
+            rtd.temperature_to_resistance(Celsius(850.0)).0,
+            390.4811,
+            1e-3,
+        );
+    }
+
+    #[test]
+    /// Test the inverse over the full range
+    fn round_trip() {
+        let rtd = Pt1000::new();
+
+        for t in -200..=850 {
+            let t = Celsius(t as FP);
+            let r = rtd.temperature_to_resistance(t);
+            compare(rtd.resistance_to_temperature(r).0, t.0, 1e-3);
+        }
+
+        compare(
+            rtd.resistance_to_temperature(Ohms(1000.0)).0,
+            0.0,
+            1e-6,
+        );
+    }
+}
@@ -0,0 +1,811 @@
+//! Thermocouple type selected at runtime.
+
+use crate::error::ParseThermocoupleError;
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
+use crate::KType;
+use crate::{
+    BType, Celsius, Delisle, EType, Fahrenheit, JType, Kelvin,
+    Millivolts, NType, Newton, RType, RangeError, Rankine, Reading,
+    Reaumur, Romer, SType, TType, ThermocoupleCore, ThermocoupleError,
+    ThermocoupleRange, ToleranceClass, WithHysteresis, FP,
+};
+use core::convert::TryFrom;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+
+/// Thermocouple type, without any instance configuration. Can be used
+/// as a map key, for example to look up per-type calibration data.
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum ThermocoupleType {
+    /// Type B thermocouple
+    B,
+    /// Type E thermocouple
+    E,
+    /// Type J thermocouple
+    J,
+    /// Type K thermocouple
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    K,
+    /// Type N thermocouple
+    N,
+    /// Type R thermocouple
+    R,
+    /// Type S thermocouple
+    S,
+    /// Type T thermocouple
+    T,
+}
+
+impl ThermocoupleType {
+    /// Every supported type
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    pub const ALL: &'static [ThermocoupleType] = &[
+        ThermocoupleType::B,
+        ThermocoupleType::E,
+        ThermocoupleType::J,
+        ThermocoupleType::K,
+        ThermocoupleType::N,
+        ThermocoupleType::R,
+        ThermocoupleType::S,
+        ThermocoupleType::T,
+    ];
+    /// Every supported type
+    #[cfg(not(any(feature = "k-type", feature = "no-libm")))]
+    pub const ALL: &'static [ThermocoupleType] = &[
+        ThermocoupleType::B,
+        ThermocoupleType::E,
+        ThermocoupleType::J,
+        ThermocoupleType::N,
+        ThermocoupleType::R,
+        ThermocoupleType::S,
+        ThermocoupleType::T,
+    ];
+
+    /// Returns the range of temperatures over which this type is
+    /// defined
+    pub fn temperature_range(self) -> (Celsius, Celsius) {
+        match self {
+            ThermocoupleType::B => BType::TEMPERATURE_RANGE,
+            ThermocoupleType::E => EType::TEMPERATURE_RANGE,
+            ThermocoupleType::J => JType::TEMPERATURE_RANGE,
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            ThermocoupleType::K => KType::TEMPERATURE_RANGE,
+            ThermocoupleType::N => NType::TEMPERATURE_RANGE,
+            ThermocoupleType::R => RType::TEMPERATURE_RANGE,
+            ThermocoupleType::S => SType::TEMPERATURE_RANGE,
+            ThermocoupleType::T => TType::TEMPERATURE_RANGE,
+        }
+    }
+    /// Returns the letter for this type, for example 'K'
+    pub const fn letter(self) -> char {
+        match self {
+            ThermocoupleType::B => 'B',
+            ThermocoupleType::E => 'E',
+            ThermocoupleType::J => 'J',
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            ThermocoupleType::K => 'K',
+            ThermocoupleType::N => 'N',
+            ThermocoupleType::R => 'R',
+            ThermocoupleType::S => 'S',
+            ThermocoupleType::T => 'T',
+        }
+    }
+    /// Returns the type with letter `c`, ignoring case
+    pub fn from_letter(c: char) -> Option<ThermocoupleType> {
+        match c.to_ascii_uppercase() {
+            'B' => Some(ThermocoupleType::B),
+            'E' => Some(ThermocoupleType::E),
+            'J' => Some(ThermocoupleType::J),
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            'K' => Some(ThermocoupleType::K),
+            'N' => Some(ThermocoupleType::N),
+            'R' => Some(ThermocoupleType::R),
+            'S' => Some(ThermocoupleType::S),
+            'T' => Some(ThermocoupleType::T),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the type that is defined from `t_min` to `t_max` with the
+/// highest mean sensitivity over that span, or `None` if no type
+/// covers it.
+pub fn recommend_type(
+    t_min: Celsius,
+    t_max: Celsius,
+) -> Option<ThermocoupleType> {
+    let sensitivity = |kind: ThermocoupleType| {
+        let thermocouple = Thermocouple::from(kind);
+
+        match t_max > t_min {
+            true => {
+                let e_min: Millivolts =
+                    thermocouple.sense_voltage(t_min);
+                let e_max: Millivolts =
+                    thermocouple.sense_voltage(t_max);
+                (e_max - e_min).0 / (t_max - t_min).0
+            }
+            false => thermocouple.seebeck_coefficient(t_min),
+        }
+    };
+
+    ThermocoupleType::ALL
+        .iter()
+        .copied()
+        .filter(|kind| {
+            let (min, max) = kind.temperature_range();
+            t_min >= min && t_max <= max && t_min <= t_max
+        })
+        .map(|kind| (kind, sensitivity(kind)))
+        .max_by(|(_, a), (_, b)| {
+            a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal)
+        })
+        .map(|(kind, _)| kind)
+}
+
+/// Fills `out` with the thermocouple temperature for each pair of type
+/// and thermoelectric potential in `voltage_by_type`, for example from
+/// a panel with one probe of each type. The reference junctions are
+/// assumed to be at 25ºC / 298.15K. The two slices should be the same
+/// length.
+pub fn sense_all_types(
+    voltage_by_type: &[(ThermocoupleType, Millivolts)],
+    out: &mut [Celsius],
+) {
+    debug_assert_eq!(voltage_by_type.len(), out.len());
+
+    for ((kind, voltage), temperature) in
+        voltage_by_type.iter().zip(out.iter_mut())
+    {
+        *temperature =
+            Thermocouple::from(*kind).sense_temperature(*voltage);
+    }
+}
+
+/// Returns E(T) for a thermocouple of type `kind`, with the reference
+/// junction at 0ºC, every `step` across its temperature range. The
+/// final step may be shorter, so that the table ends at the top of the
+/// range. Intended for generating tables on a host, for example to
+/// write to a C header.
+///
+/// This is the `curve` of the type selected at runtime.
+#[cfg(feature = "std")]
+pub fn dump_table(
+    kind: ThermocoupleType,
+    step: Celsius,
+) -> impl Iterator<Item = (Celsius, Millivolts)> {
+    use std::boxed::Box;
+
+    let curve: Box<dyn Iterator<Item = (Celsius, Millivolts)>> =
+        match kind {
+            ThermocoupleType::B => Box::new(BType::curve(step)),
+            ThermocoupleType::E => Box::new(EType::curve(step)),
+            ThermocoupleType::J => Box::new(JType::curve(step)),
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            ThermocoupleType::K => Box::new(KType::curve(step)),
+            ThermocoupleType::N => Box::new(NType::curve(step)),
+            ThermocoupleType::R => Box::new(RType::curve(step)),
+            ThermocoupleType::S => Box::new(SType::curve(step)),
+            ThermocoupleType::T => Box::new(TType::curve(step)),
+        };
+
+    curve
+}
+
+/// Strip `prefix` from the start of `s`, ignoring ASCII case
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> &'a str {
+    match s.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => {
+            &s[prefix.len()..]
+        }
+        _ => s,
+    }
+}
+
+/// Strip `suffix` from the end of `s`, ignoring ASCII case
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> &'a str {
+    let split = s.len().saturating_sub(suffix.len());
+
+    match s.get(split..) {
+        Some(tail) if tail.eq_ignore_ascii_case(suffix) => &s[..split],
+        _ => s,
+    }
+}
+
+impl FromStr for ThermocoupleType {
+    type Err = ParseThermocoupleError;
+
+    /// Parses a type letter such as "K", ignoring case. The letter may
+    /// be written with "type" before or after it, for example "type
+    /// k", "Type-K" or "k-type".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let separators: &[char] = &[' ', '-', '_'];
+
+        let s = s.trim();
+        let s = strip_prefix_ignore_case(s, "type");
+        let s = strip_suffix_ignore_case(s, "type");
+        let s = s.trim_matches(separators);
+
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => ThermocoupleType::from_letter(c),
+            _ => None,
+        }
+        .ok_or(ParseThermocoupleError)
+    }
+}
+
+/// A thermocouple whose type is selected at runtime. When serialized,
+/// the configuration is keyed by the type letter.
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug)]
+pub enum Thermocouple {
+    /// Type B thermocouple
+    B(BType),
+    /// Type E thermocouple
+    E(EType),
+    /// Type J thermocouple
+    J(JType),
+    /// Type K thermocouple
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    K(KType),
+    /// Type N thermocouple
+    N(NType),
+    /// Type R thermocouple
+    R(RType),
+    /// Type S thermocouple
+    S(SType),
+    /// Type T thermocouple
+    T(TType),
+}
+
+/// Evaluate an expression on whichever thermocouple type is inside
+macro_rules! dispatch {
+    ($self:expr, $tc:ident => $e:expr) => {
+        match $self {
+            Thermocouple::B($tc) => $e,
+            Thermocouple::E($tc) => $e,
+            Thermocouple::J($tc) => $e,
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            Thermocouple::K($tc) => $e,
+            Thermocouple::N($tc) => $e,
+            Thermocouple::R($tc) => $e,
+            Thermocouple::S($tc) => $e,
+            Thermocouple::T($tc) => $e,
+        }
+    };
+}
+
+impl Thermocouple {
+    /// New thermocouple instance for the type with letter `c`, for
+    /// example 'K'. The reference junction is assumed to be at 25ºC /
+    /// 298.15K.
+    ///
+    /// Returns `None` if `c` is not a supported type.
+    pub fn from_letter(c: char) -> Option<Thermocouple> {
+        ThermocoupleType::from_letter(c).map(Thermocouple::from)
+    }
+    /// New thermocouple instance for the type with letter `c`, with the
+    /// reference junction at `reference_temperature`.
+    ///
+    /// Returns `None` if `c` is not a supported type.
+    pub fn new<T>(
+        c: char,
+        reference_temperature: T,
+    ) -> Option<Thermocouple>
+    where
+        T: Into<Celsius>,
+    {
+        Thermocouple::from_letter(c).map(|tc| {
+            tc.with_reference_temperature(reference_temperature)
+        })
+    }
+    /// Sets the reference junction temperature used.
+    pub fn with_reference_temperature<T>(
+        self,
+        reference_temperature: T,
+    ) -> Self
+    where
+        T: Into<Celsius>,
+    {
+        dispatch!(self, tc => {
+            tc.with_reference_temperature(reference_temperature).into()
+        })
+    }
+    /// Returns the type of this thermocouple
+    pub fn kind(&self) -> ThermocoupleType {
+        dispatch!(self, tc => tc.kind())
+    }
+    /// Returns the reference junction temperature. If the reference
+    /// junction potential was set directly, this inverts it, which is
+    /// meaningless for a Type B thermocouple with its reference
+    /// junction near room temperature.
+    pub fn reference_temperature(&self) -> Celsius {
+        dispatch!(self, tc => tc.reference_temperature())
+    }
+    /// Returns the Seebeck coefficient dE/dT at a given thermocouple
+    /// temperature, in microvolts per degree Celsius.
+    pub fn seebeck_coefficient<T>(&self, temperature: T) -> FP
+    where
+        T: Into<Celsius>,
+    {
+        dispatch!(self, tc => tc.seebeck_coefficient(temperature))
+    }
+    /// Returns the temperature resolution at a given thermocouple
+    /// temperature, for an ADC with a resolution of `adc_lsb`.
+    pub fn temperature_resolution<T>(
+        &self,
+        temperature: T,
+        adc_lsb: Millivolts,
+    ) -> Celsius
+    where
+        T: Into<Celsius>,
+    {
+        dispatch!(self, tc => {
+            tc.temperature_resolution(temperature, adc_lsb)
+        })
+    }
+    /// Returns the thermoelectric potential of the reference (cold)
+    /// junction at a given temperature.
+    pub fn cold_junction_potential<T>(
+        &self,
+        reference_temperature: T,
+    ) -> Millivolts
+    where
+        T: Into<Celsius>,
+    {
+        match self {
+            Thermocouple::B(_) => {
+                BType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::E(_) => {
+                EType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::J(_) => {
+                JType::cold_junction_potential(reference_temperature)
+            }
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            Thermocouple::K(_) => {
+                KType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::N(_) => {
+                NType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::R(_) => {
+                RType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::S(_) => {
+                SType::cold_junction_potential(reference_temperature)
+            }
+            Thermocouple::T(_) => {
+                TType::cold_junction_potential(reference_temperature)
+            }
+        }
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, with the reference junction at
+    /// `reference_temperature` rather than the temperature this
+    /// instance was constructed with.
+    #[doc(alias = "sense_temperature_ref")]
+    pub fn sense_temperature_with_reference<W, T>(
+        &self,
+        voltage: Millivolts,
+        reference_temperature: T,
+    ) -> W
+    where
+        Celsius: Into<W>,
+        T: Into<Celsius>,
+    {
+        dispatch!(self, tc => {
+            tc.sense_temperature_with_reference(
+                voltage,
+                reference_temperature,
+            )
+        })
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, where the reference junction temperature is itself
+    /// measured by `cold_junction_voltage` from a second thermocouple
+    /// of the same type referenced to 0ºC.
+    pub fn sense_temperature_from_raw<W>(
+        &self,
+        measured: Millivolts,
+        cold_junction_voltage: Millivolts,
+    ) -> W
+    where
+        Celsius: Into<W>,
+    {
+        dispatch!(self, tc => {
+            tc.sense_temperature_from_raw(measured, cold_junction_voltage)
+        })
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, where the reference junction temperature is itself
+    /// measured by `cold_junction_voltage`, or an error if either
+    /// potential is out of range.
+    pub fn try_sense_temperature_from_raw<W>(
+        &self,
+        measured: Millivolts,
+        cold_junction_voltage: Millivolts,
+    ) -> Result<W, ThermocoupleError>
+    where
+        Celsius: Into<W>,
+    {
+        dispatch!(self, tc => {
+            tc.try_sense_temperature_from_raw(measured, cold_junction_voltage)
+        })
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, refined by Newton-Raphson iteration on the reference
+    /// function.
+    pub fn sense_temperature_refined<W>(&self, voltage: Millivolts) -> W
+    where
+        Celsius: Into<W>,
+    {
+        dispatch!(self, tc => tc.sense_temperature_refined(voltage))
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, along with the reference junction potential and the
+    /// total potential, for diagnostics.
+    pub fn sense_temperature_verbose<W>(
+        &self,
+        voltage: Millivolts,
+    ) -> Reading<W>
+    where
+        Celsius: Into<W>,
+    {
+        dispatch!(self, tc => tc.sense_temperature_verbose(voltage))
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, in integer milli-degrees Celsius, or `None` if the
+    /// temperature is NaN.
+    pub fn sense_temperature_millidegrees(
+        &self,
+        voltage: Millivolts,
+    ) -> Option<i32> {
+        dispatch!(self, tc => tc.sense_temperature_millidegrees(voltage))
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential in Celsius, Kelvin and Fahrenheit.
+    pub fn sense_temperature_multi(
+        &self,
+        voltage: Millivolts,
+    ) -> (Celsius, Kelvin, Fahrenheit) {
+        dispatch!(self, tc => tc.sense_temperature_multi(voltage))
+    }
+    /// Returns false if a measured thermoelectric potential lies outside
+    /// the range of the inverse function, for example when the
+    /// thermocouple is open circuit.
+    pub fn is_plausible(&self, voltage: Millivolts) -> bool {
+        dispatch!(self, tc => tc.is_plausible(voltage))
+    }
+    /// Returns true if `sense_temperature` would succeed for a given
+    /// thermoelectric potential.
+    pub fn in_range(&self, voltage: Millivolts) -> bool {
+        dispatch!(self, tc => tc.in_range(voltage))
+    }
+    /// Returns the thermocouple temperature for a given thermoelectric
+    /// potential, or `None` if the potential is not plausible.
+    pub fn sense_temperature_checked<W>(
+        &self,
+        voltage: Millivolts,
+    ) -> Option<W>
+    where
+        Celsius: Into<W>,
+    {
+        dispatch!(self, tc => tc.sense_temperature_checked(voltage))
+    }
+    /// Adds a hysteresis filter with a deadband of `band`, so that the
+    /// sensed temperature only changes when it moves by more than
+    /// `band`.
+    pub fn with_hysteresis<W>(self, band: W) -> WithHysteresis<Self, W>
+    where
+        Self: ThermocoupleCore<W>,
+        W: Copy + PartialOrd + Add<Output = W> + Sub<Output = W>,
+    {
+        WithHysteresis::new(self, band)
+    }
+    /// Returns the permissible deviation at a given thermocouple
+    /// temperature for an IEC 60584-1 tolerance class, or `None` if the
+    /// class is not defined at that temperature.
+    pub fn tolerance<T>(
+        &self,
+        temperature: T,
+        class: ToleranceClass,
+    ) -> Option<Celsius>
+    where
+        T: Into<Celsius>,
+    {
+        dispatch!(self, tc => tc.tolerance(temperature, class))
+    }
+}
+
+impl From<ThermocoupleType> for Thermocouple {
+    /// New thermocouple instance of the given type. The reference
+    /// junction is assumed to be at 25ºC / 298.15K.
+    fn from(ty: ThermocoupleType) -> Thermocouple {
+        match ty {
+            ThermocoupleType::B => Thermocouple::B(BType::new()),
+            ThermocoupleType::E => Thermocouple::E(EType::new()),
+            ThermocoupleType::J => Thermocouple::J(JType::new()),
+            #[cfg(any(feature = "k-type", feature = "no-libm"))]
+            ThermocoupleType::K => Thermocouple::K(KType::new()),
+            ThermocoupleType::N => Thermocouple::N(NType::new()),
+            ThermocoupleType::R => Thermocouple::R(RType::new()),
+            ThermocoupleType::S => Thermocouple::S(SType::new()),
+            ThermocoupleType::T => Thermocouple::T(TType::new()),
+        }
+    }
+}
+
+impl FromStr for Thermocouple {
+    type Err = ParseThermocoupleError;
+
+    /// Parses a thermocouple type as for `ThermocoupleType`. The
+    /// reference junction is assumed to be at 25ºC / 298.15K.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ThermocoupleType>().map(Thermocouple::from)
+    }
+}
+
+macro_rules! conversions {
+    ($($(#[$attr:meta])* $Type:ident => $variant:ident;)*) => {
+        $(
+            $(#[$attr])*
+            impl From<$Type> for Thermocouple {
+                fn from(tc: $Type) -> Thermocouple {
+                    Thermocouple::$variant(tc)
+                }
+            }
+            $(#[$attr])*
+            impl TryFrom<Thermocouple> for $Type {
+                type Error = Thermocouple;
+
+                /// Returns the thermocouple instance, or the runtime
+                /// type unchanged if it is a different variant.
+                fn try_from(
+                    tc: Thermocouple,
+                ) -> Result<$Type, Self::Error> {
+                    match tc {
+                        Thermocouple::$variant(tc) => Ok(tc),
+                        tc => Err(tc),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+conversions! {
+    BType => B;
+    EType => E;
+    JType => J;
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    KType => K;
+    NType => N;
+    RType => R;
+    SType => S;
+    TType => T;
+}
+
+macro_rules! thermocouple_core {
+    ($($unit:ty),+) => {
+        $(
+            impl ThermocoupleCore<$unit> for Thermocouple {
+                fn sense_temperature(&self, voltage: Millivolts) -> $unit {
+                    dispatch!(self, tc => tc.sense_temperature(voltage))
+                }
+                fn try_sense_temperature(
+                    &self,
+                    voltage: Millivolts,
+                ) -> Result<$unit, ThermocoupleError> {
+                    dispatch!(self, tc => tc.try_sense_temperature(voltage))
+                }
+                fn sense_voltage(&self, temperature: $unit) -> Millivolts {
+                    dispatch!(self, tc => tc.sense_voltage(temperature))
+                }
+                fn try_sense_voltage(
+                    &self,
+                    temperature: $unit,
+                ) -> Result<Millivolts, RangeError<Celsius>> {
+                    dispatch!(self, tc => tc.try_sense_voltage(temperature))
+                }
+            }
+        )+
+    };
+}
+
+thermocouple_core! {
+    Celsius, Kelvin, Fahrenheit, Rankine, Reaumur, Newton, Delisle, Romer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        recommend_type, sense_all_types, Thermocouple, ThermocoupleType,
+    };
+    use crate::error::ParseThermocoupleError;
+    use crate::{
+        Celsius, JType, Millivolts, SType, TType, ThermocoupleCore,
+    };
+    use core::convert::TryFrom;
+
+    #[test]
+    /// Test converting between the runtime type and the static types
+    fn try_from() {
+        let thermocouple = Thermocouple::from(JType::new());
+        assert_eq!(thermocouple.kind(), ThermocoupleType::J);
+
+        assert!(JType::try_from(thermocouple).is_ok());
+        assert_eq!(
+            TType::try_from(thermocouple).unwrap_err().kind(),
+            ThermocoupleType::J
+        );
+    }
+
+    #[test]
+    /// Test sensing one probe of each type matches the runtime type
+    fn sense_all() {
+        let voltage_by_type: std::vec::Vec<_> = ThermocoupleType::ALL
+            .iter()
+            .map(|kind| (*kind, Millivolts(1.1)))
+            .collect();
+        let mut temperatures = [Celsius(0.0); 8];
+        let temperatures = &mut temperatures[..voltage_by_type.len()];
+
+        sense_all_types(&voltage_by_type, temperatures);
+
+        for ((kind, voltage), temperature) in
+            voltage_by_type.iter().zip(temperatures.iter())
+        {
+            let expected: Celsius =
+                Thermocouple::from(*kind).sense_temperature(*voltage);
+            assert_eq!(*temperature, expected);
+        }
+    }
+
+    #[test]
+    /// Test the runtime type gives the same result as the static type
+    fn from_letter() {
+        let thermocouple = Thermocouple::from_letter('j').unwrap();
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+
+        assert_eq!(
+            temperature,
+            JType::new().sense_temperature(Millivolts(1.1))
+        );
+    }
+
+    #[test]
+    /// Test constructing from a letter with a reference temperature
+    fn new() {
+        let thermocouple =
+            Thermocouple::new('S', Celsius(0.0)).unwrap();
+        let temperature: Celsius =
+            thermocouple.sense_temperature(Millivolts(1.1));
+
+        assert_eq!(thermocouple.kind(), ThermocoupleType::S);
+        let expected =
+            SType::new().with_reference_temperature(Celsius(0.0));
+        assert_eq!(
+            temperature,
+            expected.sense_temperature(Millivolts(1.1))
+        );
+        assert!(Thermocouple::new('X', Celsius(0.0)).is_none());
+    }
+
+    #[test]
+    fn from_letter_unknown() {
+        assert!(Thermocouple::from_letter('X').is_none());
+    }
+
+    #[test]
+    /// Test parsing type letters with and without a "type" prefix
+    fn parse_type() {
+        assert_eq!(
+            "j".parse::<ThermocoupleType>(),
+            Ok(ThermocoupleType::J)
+        );
+        assert_eq!("S".parse(), Ok(ThermocoupleType::S));
+        assert_eq!("type-j".parse(), Ok(ThermocoupleType::J));
+        assert_eq!("Type T".parse(), Ok(ThermocoupleType::T));
+        assert_eq!(" n_type ".parse(), Ok(ThermocoupleType::N));
+    }
+
+    #[test]
+    fn parse_type_unknown() {
+        for s in &["", "x", "type", "type x", "jj", "typed"] {
+            assert_eq!(
+                s.parse::<ThermocoupleType>(),
+                Err(ParseThermocoupleError)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "use_serde")]
+    /// Test the runtime type is serialized keyed by type letter
+    fn serde_round_trip() {
+        let thermocouple = Thermocouple::from_letter('T').unwrap();
+        let json = serde_json::to_string(&thermocouple).unwrap();
+        assert!(json.starts_with(r#"{"T":{"reference_potential":"#));
+
+        let thermocouple: Thermocouple =
+            serde_json::from_str(&json).unwrap();
+        assert!(matches!(thermocouple, Thermocouple::T(_)));
+    }
+
+    #[test]
+    /// Test looking up per-type data by kind
+    fn kind_map_key() {
+        use std::collections::HashMap;
+
+        let mut offsets = HashMap::new();
+        let _ = offsets.insert(ThermocoupleType::J, 0.5);
+        let _ = offsets.insert(ThermocoupleType::T, -0.2);
+
+        assert_eq!(offsets.get(&JType::new().kind()), Some(&0.5));
+        let thermocouple = Thermocouple::from_letter('T').unwrap();
+        assert_eq!(offsets.get(&thermocouple.kind()), Some(&-0.2));
+        assert_eq!(offsets.get(&ThermocoupleType::B), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg(not(feature = "reduced-order"))]
+    #[test]
+    /// Test generating a table of E(T)
+    fn dump_table() {
+        use crate::tests::compare;
+
+        let table: std::vec::Vec<_> =
+            super::dump_table(ThermocoupleType::J, Celsius(1.0))
+                .collect();
+
+        // -210ºC to 1200ºC inclusive
+        assert_eq!(table.len(), 1411);
+        assert_eq!(table[0].0, Celsius(-210.0));
+        compare(table[0].1 .0, -8.095, 0.0005);
+        assert_eq!(table[1410].0, Celsius(1200.0));
+        compare(table[1410].1 .0, 69.553, 0.0005);
+
+        // Shorter final step
+        let table: std::vec::Vec<_> =
+            super::dump_table(ThermocoupleType::J, Celsius(100.0))
+                .collect();
+        assert_eq!(table.len(), 16);
+        assert_eq!(table[14].0, Celsius(1190.0));
+        assert_eq!(table[15].0, Celsius(1200.0));
+    }
+
+    #[test]
+    fn recommend() {
+        // Only Type B reaches 1800ºC
+        assert_eq!(
+            recommend_type(Celsius(0.0), Celsius(1800.0)),
+            Some(ThermocoupleType::B)
+        );
+        // Type E has the highest sensitivity of the base metal types
+        assert_eq!(
+            recommend_type(Celsius(0.0), Celsius(300.0)),
+            Some(ThermocoupleType::E)
+        );
+        assert_eq!(recommend_type(Celsius(0.0), Celsius(2000.0)), None);
+        assert_eq!(recommend_type(Celsius(300.0), Celsius(0.0)), None);
+    }
+
+    #[test]
+    fn parse_thermocouple() {
+        let thermocouple: Thermocouple = "type e".parse().unwrap();
+        assert!(matches!(thermocouple, Thermocouple::E(_)));
+    }
+}
@@ -1,6 +1,9 @@
 //! S-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_E_BELOW_1064_18: [FP; 9] = [
     0.000000000000E+00,
     0.540313308631E-02,
@@ -12,6 +15,7 @@ const S_TYPE_E_BELOW_1064_18: [FP; 9] = [
     -0.125068871393E-19,
     0.271443176145E-23,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 5] = [
     0.132900444085E+01,
     0.334509311344E-02,
@@ -19,6 +23,7 @@ const S_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 5] = [
     -0.164856259209E-08,
     0.129989605174E-13,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_E_ABOVE_1664_5: [FP; 5] = [
     0.146628232636E+03,
     -0.258430516752E+00,
@@ -27,6 +32,7 @@ const S_TYPE_E_ABOVE_1664_5: [FP; 5] = [
     -0.943223690612E-14,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_T0: [FP; 10] = [
     0.00000000E+00,
     1.84949460E+02,
@@ -39,6 +45,7 @@ const S_TYPE_T0: [FP; 10] = [
     -2.34181944E+01,
     2.79786260E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_T1: [FP; 10] = [
     1.291507177E+01,
     1.466298863E+02,
@@ -51,6 +58,7 @@ const S_TYPE_T1: [FP; 10] = [
     -1.447379511E-07,
     8.211272125E-09,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_T2: [FP; 10] = [
     -8.087801117E+01,
     1.621573104E+02,
@@ -63,6 +71,7 @@ const S_TYPE_T2: [FP; 10] = [
     0.000000000E+00,
     0.000000000E+00,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const S_TYPE_T3: [FP; 10] = [
     5.333875126E+04,
     -1.235892298E+04,
@@ -76,70 +85,283 @@ const S_TYPE_T3: [FP; 10] = [
     0.000000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const S_TYPE_E_BELOW_1064_18: [FP; 6] = [
+    0.1894287729E-02,
+    0.5409244676E-02,
+    0.1177200057E-04,
+    -0.1711729099E-07,
+    0.1370250433E-10,
+    -0.4178561339E-14,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_E_ABOVE_1064_18_BELOW_1664_5: [FP; 3] = [
+    -0.2574610802E+01,
+    0.1217781749E-01,
+    -0.5382080377E-07,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_E_ABOVE_1664_5: [FP; 2] = [
+    -0.1072714461E+01,
+    0.1118462868E-01,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_T0: [FP; 6] = [
+    -0.9025195747E-01,
+    0.1869181241E+03,
+    -0.7942167787E+02,
+    0.6223139899E+02,
+    -0.2864092129E+02,
+    0.5310806059E+01,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_T1: [FP; 6] = [
+    0.2740526497E+02,
+    0.1276477094E+03,
+    -0.5577605088E+01,
+    0.5713851103E+00,
+    -0.3915992372E-01,
+    0.1096901766E-02,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_T2: [FP; 6] = [
+    -0.8475949211E+02,
+    0.1634762871E+03,
+    -0.8715343876E+01,
+    0.4839899806E+00,
+    -0.1482001874E-01,
+    0.2135449600E-03,
+];
+#[cfg(feature = "reduced-order")]
+const S_TYPE_T3: [FP; 6] = [
+    -0.5028852424E+06,
+    0.1412176201E+06,
+    -0.1586658803E+05,
+    0.8936207439E+03,
+    -0.2521687483E+02,
+    0.2852597464E+00,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &S_TYPE_E_BELOW_1064_18,
+    &S_TYPE_E_ABOVE_1064_18_BELOW_1664_5,
+    &S_TYPE_E_ABOVE_1664_5,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &S_TYPE_T0,
+    &S_TYPE_T1,
+    &S_TYPE_T2,
+    &S_TYPE_T3,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    S_TYPE_E_BELOW_1064_18[0] == 0.0
+        && S_TYPE_T0[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0, b1.0], E_STEP_MAX));
+
+    let [v0, v1, v2] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(
+        T_COEFFICIENTS,
+        &[v0.0, v1.0, v2.0],
+        T_STEP_MAX
+    ));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-50.0), Celsius(1768.1));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-0.236), Millivolts(18.694));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-0.235), Millivolts(18.693));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.00056;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 2] =
+    [Celsius(1064.18), Celsius(1664.5)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 3] =
+    [Millivolts(1.874), Millivolts(11.950), Millivolts(17.536)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(100.0), Millivolts(0.646)),
+    (Celsius(700.0), Millivolts(6.275)),
+    (Celsius(1400.0), Millivolts(14.373)),
+    (Celsius(1700.0), Millivolts(17.947)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a S-Type thermocouple in the range -50ºC to
 /// 1768.1ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -50.0);
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 1768.1);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
+
+    e_unchecked(t)
+}
 
-    let e = match (t > 1064.18, t > 1664.5) {
+/// Evaluate E(T) for a S-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    let e = match (t > b0.0, t > b1.0) {
         (false, _) => {
             // -50ºC -> 1064.18ºC
-            const C: [FP; 9] = S_TYPE_E_BELOW_1064_18;
+            let c = S_TYPE_E_BELOW_1064_18;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
         (true, false) => {
             // 1064.18ºC -> 1664.5ºC
-            const C: [FP; 5] = S_TYPE_E_ABOVE_1064_18_BELOW_1664_5;
+            let c = S_TYPE_E_ABOVE_1064_18_BELOW_1664_5;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
+            polyval(c, t)
         }
         (true, true) => {
             // 1664.5ºC -> 1768.1ºC
-            const C: [FP; 5] = S_TYPE_E_ABOVE_1664_5;
+            let c = S_TYPE_E_ABOVE_1664_5;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a S-Type thermocouple in
+/// the range -50ºC to 1768.1ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// S-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0, b1] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match (t > b0.0, t > b1.0) {
+        (false, _) => polyval_with_derivative(S_TYPE_E_BELOW_1064_18, t),
+        (true, false) => polyval_with_derivative(S_TYPE_E_ABOVE_1064_18_BELOW_1664_5, t),
+        (true, true) => polyval_with_derivative(S_TYPE_E_ABOVE_1664_5, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a S-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (0.0..=1100.0).contains(&t) => 1.0,
+        ToleranceClass::One if (1100.0..=1600.0).contains(&t) => {
+            1.0 + 0.003 * (t - 1100.0)
+        }
+        ToleranceClass::Two if (0.0..=1600.0).contains(&t) => {
+            deviation(t, 1.5, 0.0025)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// IEC 60584-3 defines only compensating cable for S-Type
+/// thermocouples, not extension wire
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> = None;
+
+/// Always returns `None`, as IEC 60584-3 defines only compensating
+/// cable for S-Type thermocouples, not extension wire.
+pub fn extension_tolerance(_class: ToleranceClass) -> Option<Celsius> {
+    None
+}
+
 /// Evaluate T for a S-Type thermocouple given E(T) in the range
 /// -0.235mV to 18.693mV, where T is in Celsius and E(T) is in millivolts.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.00056; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -0.235 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 18.693 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match (e < 1.874, e < 11.950, e < 17.536) {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a S-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a S-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0, b1, b2] = INVERSE_BREAKPOINTS;
+    let c = match (e < b0.0, e < b1.0, e < b2.0) {
         (true, _, _) => S_TYPE_T0,
         (false, true, _) => S_TYPE_T1,
         (false, false, true) => S_TYPE_T2,
@@ -147,16 +369,7 @@ pub fn t(e: Millivolts) -> Celsius {
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e
-        + c[8] * e * e * e * e * e * e * e * e
-        + c[9] * e * e * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -166,6 +379,10 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 1.0;
+
     nist_its_90! {
         // NIST inverse function is defined over full range
         "../nist/type_s.tab.rs", -50, 1768, |_| true
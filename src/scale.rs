@@ -0,0 +1,109 @@
+//! Temperature scales.
+//!
+//! The NIST reference functions are defined on the International
+//! Temperature Scale of 1990 (ITS-90). Older equipment and datasheets
+//! may instead use the International Practical Temperature Scale of
+//! 1968 (IPTS-68). The difference is given by the conversion functions
+//! published by Rusby et al., Metrologia 28 (1991).
+
+use crate::polyval::polyval;
+use crate::{Celsius, FP};
+
+/// Temperature scale for sensed temperatures
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum TemperatureScale {
+    /// International Temperature Scale of 1990
+    #[default]
+    Its90,
+    /// International Practical Temperature Scale of 1968
+    Ipts68,
+}
+
+/// t90 - t68 from -200ºC to 630.615ºC, as a polynomial in t90 / 630ºC
+const T90_T68_BELOW_630_615: [FP; 9] = [
+    0.0, -0.148759, -0.267408, 1.080760, 1.269056, -4.089591,
+    -1.871251, 7.438081, -3.536296,
+];
+/// t90 - t68 from 630.615ºC to 1064.18ºC, as a polynomial in t90
+const T90_T68_BELOW_1064_18: [FP; 6] = [
+    7.8687209E+01,
+    -4.7135991E-01,
+    1.0954715E-03,
+    -1.2357884E-06,
+    6.7736583E-10,
+    -1.4458081E-13,
+];
+
+/// Evaluate t90 - t68 at a given ITS-90 temperature. No correction is
+/// applied below -200ºC.
+fn difference(t90: Celsius) -> FP {
+    let t = t90.0;
+
+    if t < -200.0 {
+        0.0
+    } else if t < 630.615 {
+        polyval(T90_T68_BELOW_630_615, t / 630.0)
+    } else if t < 1064.18 {
+        polyval(T90_T68_BELOW_1064_18, t)
+    } else {
+        // Above the freezing point of gold, in Kelvin
+        let ratio = (t + 273.15) / 1337.33;
+        -0.25 * ratio * ratio
+    }
+}
+
+impl TemperatureScale {
+    /// Converts an ITS-90 temperature to this scale
+    pub fn from_its90(self, t90: Celsius) -> Celsius {
+        match self {
+            TemperatureScale::Its90 => t90,
+            TemperatureScale::Ipts68 => {
+                Celsius(t90.0 - difference(t90))
+            }
+        }
+    }
+    /// Converts a temperature on this scale to ITS-90
+    pub fn to_its90(self, t: Celsius) -> Celsius {
+        match self {
+            TemperatureScale::Its90 => t,
+            TemperatureScale::Ipts68 => {
+                // The difference changes slowly, so a couple of
+                // fixed-point iterations are plenty
+                let mut t90 = t;
+                for _ in 0..3 {
+                    t90 = Celsius(t.0 + difference(t90));
+                }
+                t90
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemperatureScale;
+    use crate::tests::compare;
+    use crate::Celsius;
+
+    #[test]
+    /// Test against tabulated values of t90 - t68
+    fn ipts68() {
+        let scale = TemperatureScale::Ipts68;
+
+        for &(t90, difference) in &[
+            (100.0, -0.026),
+            (500.0, -0.079),
+            (700.0, -0.023),
+            (1000.0, -0.205),
+        ] {
+            let t68 = scale.from_its90(Celsius(t90));
+            compare(t90 - t68.0, difference, 0.001);
+            compare(scale.to_its90(t68).0, t90, 1e-4);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Runtime self test of the reference functions.
+//!
+//! Each thermocouple type checks E(T) and its inverse against a few
+//! values from the NIST ITS-90 tables. Unlike the unit tests, this is
+//! intended to run on the target, for example at power on.
+
+use crate::error::SelfTestError;
+#[cfg(any(feature = "k-type", feature = "no-libm"))]
+use crate::KType;
+use crate::{BType, EType, JType, NType, RType, SType, TType, FP};
+
+/// Maximum error at each anchor point, in Celsius
+#[cfg(not(feature = "reduced-order"))]
+pub(crate) const TOLERANCE: FP = 0.25;
+#[cfg(feature = "reduced-order")]
+pub(crate) const TOLERANCE: FP = 1.0;
+
+/// Checks the reference functions of every thermocouple type against
+/// known values. Returns the first type that fails.
+pub fn self_test() -> Result<(), SelfTestError> {
+    BType::self_test()?;
+    EType::self_test()?;
+    JType::self_test()?;
+    #[cfg(any(feature = "k-type", feature = "no-libm"))]
+    KType::self_test()?;
+    NType::self_test()?;
+    RType::self_test()?;
+    SType::self_test()?;
+    TType::self_test()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::self_test;
+
+    #[test]
+    fn passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+}
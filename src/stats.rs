@@ -0,0 +1,217 @@
+//! Statistics of temperatures, running or over slices.
+
+use crate::{Celsius, FP};
+
+/// Running minimum, maximum, mean and standard deviation of a stream
+/// of temperatures, without storing the samples. The mean and variance
+/// are updated with Welford's online algorithm, which is numerically
+/// stable for long streams.
+#[derive(Clone, Copy, Debug)]
+pub struct TemperatureStats<W = Celsius> {
+    count: u32,
+    min: Option<W>,
+    max: Option<W>,
+    mean: FP,
+    /// Sum of squared differences from the mean
+    m2: FP,
+}
+
+impl<W> Default for TemperatureStats<W> {
+    fn default() -> Self {
+        TemperatureStats {
+            count: 0,
+            min: None,
+            max: None,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+}
+
+impl<W> TemperatureStats<W>
+where
+    W: Copy + PartialOrd + From<FP>,
+    FP: From<W>,
+{
+    /// New accumulator, with no samples
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a sample
+    pub fn push(&mut self, t: W) {
+        self.count += 1;
+
+        self.min = match self.min {
+            Some(min) if min <= t => Some(min),
+            _ => Some(t),
+        };
+        self.max = match self.max {
+            Some(max) if max >= t => Some(max),
+            _ => Some(t),
+        };
+
+        let x = FP::from(t);
+        let delta = x - self.mean;
+        self.mean += delta / (self.count as FP);
+        self.m2 += delta * (x - self.mean);
+    }
+    /// Returns the number of samples
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+    /// Returns the lowest sample, or `None` if there are no samples
+    pub fn min(&self) -> Option<W> {
+        self.min
+    }
+    /// Returns the highest sample, or `None` if there are no samples
+    pub fn max(&self) -> Option<W> {
+        self.max
+    }
+    /// Returns the mean of the samples, or `None` if there are no
+    /// samples
+    pub fn mean(&self) -> Option<W> {
+        match self.count {
+            0 => None,
+            _ => Some(W::from(self.mean)),
+        }
+    }
+    /// Returns the population standard deviation of the samples, or
+    /// `None` if there are no samples
+    pub fn std_dev(&self) -> Option<W> {
+        match self.count {
+            0 => None,
+            n => Some(W::from(sqrt(self.m2 / (n as FP)))),
+        }
+    }
+}
+
+/// Values in `values` that are not NaN
+fn valid<W>(values: &[W]) -> impl Iterator<Item = W> + '_
+where
+    W: Copy,
+    FP: From<W>,
+{
+    values.iter().copied().filter(|t| !FP::from(*t).is_nan())
+}
+
+/// Returns the lowest value in `values`, ignoring NaN. Returns `None`
+/// if there are no values other than NaN.
+pub fn min<W>(values: &[W]) -> Option<W>
+where
+    W: Copy + PartialOrd,
+    FP: From<W>,
+{
+    valid(values).fold(None, |min, t| match min {
+        Some(min) if min <= t => Some(min),
+        _ => Some(t),
+    })
+}
+
+/// Returns the highest value in `values`, ignoring NaN. Returns `None`
+/// if there are no values other than NaN.
+pub fn max<W>(values: &[W]) -> Option<W>
+where
+    W: Copy + PartialOrd,
+    FP: From<W>,
+{
+    valid(values).fold(None, |max, t| match max {
+        Some(max) if max >= t => Some(max),
+        _ => Some(t),
+    })
+}
+
+/// Returns the mean of `values`, ignoring NaN. Returns `None` if there
+/// are no values other than NaN.
+pub fn mean<W>(values: &[W]) -> Option<W>
+where
+    W: Copy + From<FP>,
+    FP: From<W>,
+{
+    let (sum, count) = valid(values)
+        .fold((0.0, 0), |(sum, count), t| {
+            (sum + FP::from(t), count + 1)
+        });
+
+    match count {
+        0 => None,
+        n => Some(W::from(sum / (n as FP))),
+    }
+}
+
+/// Square root by Newton's method, as `FP::sqrt` is not available in
+/// `core`
+fn sqrt(x: FP) -> FP {
+    if x.is_nan() || x == FP::INFINITY {
+        return x;
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    // Starting at or above the root, each step decreases until the
+    // root is reached
+    let mut y = match x > 1.0 {
+        true => x,
+        false => 1.0,
+    };
+    loop {
+        let next = 0.5 * (y + x / y);
+        if next >= y {
+            return y;
+        }
+        y = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max, mean, min, sqrt, TemperatureStats};
+    use crate::tests::compare;
+    use crate::{Celsius, Fahrenheit, FP};
+
+    #[test]
+    fn known_sequence() {
+        let mut stats = TemperatureStats::new();
+        for &t in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].iter() {
+            stats.push(Celsius(t));
+        }
+
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.min(), Some(Celsius(2.0)));
+        assert_eq!(stats.max(), Some(Celsius(9.0)));
+        compare(stats.mean().unwrap().0, 5.0, 1e-6);
+        compare(stats.std_dev().unwrap().0, 2.0, 1e-6);
+    }
+
+    #[test]
+    fn empty() {
+        let stats: TemperatureStats<Fahrenheit> =
+            TemperatureStats::new();
+
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.std_dev(), None);
+    }
+
+    #[test]
+    /// Test the slice functions skip NaN
+    fn slices() {
+        let readings = [Celsius(21.0), Celsius(FP::NAN), Celsius(23.0)];
+
+        assert_eq!(min(&readings), Some(Celsius(21.0)));
+        assert_eq!(max(&readings), Some(Celsius(23.0)));
+        assert_eq!(mean(&readings), Some(Celsius(22.0)));
+
+        let readings = [Celsius(FP::NAN)];
+        assert_eq!(max(&readings), None);
+        assert_eq!(mean::<Celsius>(&[]), None);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        for i in 0..1000 {
+            let x = (i as FP) * 0.37;
+            compare(sqrt(x), x.sqrt(), 1e-5);
+        }
+    }
+}
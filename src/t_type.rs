@@ -1,6 +1,9 @@
 //! T-Type thermocouple data
-use crate::{Celsius, Millivolts, FP};
+use crate::polyval::{polyval, polyval_with_derivative};
+use crate::tolerance::{deviation, ToleranceClass};
+use crate::{Celsius, Millivolts, RangeError, FP};
 
+#[cfg(not(feature = "reduced-order"))]
 const T_TYPE_E_BELOW_0: [FP; 15] = [
     0.000000000000E+00,
     0.387481063640E-01,
@@ -18,6 +21,7 @@ const T_TYPE_E_BELOW_0: [FP; 15] = [
     0.139450270620E-26,
     0.797951539270E-30,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const T_TYPE_E_ABOVE_0: [FP; 9] = [
     0.000000000000E+00,
     0.387481063640E-01,
@@ -30,6 +34,7 @@ const T_TYPE_E_ABOVE_0: [FP; 9] = [
     -0.275129016730E-19,
 ];
 
+#[cfg(not(feature = "reduced-order"))]
 const T_TYPE_T0: [FP; 8] = [
     0.0000000E+00,
     2.5949192E+01,
@@ -40,6 +45,7 @@ const T_TYPE_T0: [FP; 8] = [
     2.0241446E-02,
     1.2668171E-03,
 ];
+#[cfg(not(feature = "reduced-order"))]
 const T_TYPE_T1: [FP; 8] = [
     0.000000E+00,
     2.592800E+01,
@@ -51,110 +57,260 @@ const T_TYPE_T1: [FP; 8] = [
     0.000000E+00,
 ];
 
+// Lower-degree fits to the reference functions, used with the
+// `reduced-order` feature
+#[cfg(feature = "reduced-order")]
+const T_TYPE_E_BELOW_0: [FP; 5] = [
+    0.1260321650E-01,
+    0.4040548997E-01,
+    0.7890082470E-04,
+    0.1752741126E-06,
+    0.4396825402E-09,
+];
+#[cfg(feature = "reduced-order")]
+const T_TYPE_E_ABOVE_0: [FP; 4] = [
+    -0.1500476678E-02,
+    0.3869090761E-01,
+    0.4369557381E-04,
+    -0.2496349760E-07,
+];
+#[cfg(feature = "reduced-order")]
+const T_TYPE_T0: [FP; 5] = [
+    -0.3608531733E+00,
+    0.2413582360E+02,
+    -0.2499629090E+01,
+    -0.5610006745E+00,
+    -0.8530250323E-01,
+];
+#[cfg(feature = "reduced-order")]
+const T_TYPE_T1: [FP; 5] = [
+    0.2534481370E+00,
+    0.2549751454E+02,
+    -0.5921561899E+00,
+    0.2047605702E-01,
+    -0.3207372716E-03,
+];
+
+/// Coefficients of E(T) for each range of T, in increasing order
+pub(crate) const E_COEFFICIENTS: &[&[FP]] = &[
+    &T_TYPE_E_BELOW_0,
+    &T_TYPE_E_ABOVE_0,
+];
+/// Coefficients of T for each range of E(T), in increasing order
+pub(crate) const T_COEFFICIENTS: &[&[FP]] = &[
+    &T_TYPE_T0,
+    &T_TYPE_T1,
+];
+
+// Checks on the structure of the coefficients, at compile time
+const _: () =
+    assert!(E_COEFFICIENTS.len() == TEMPERATURE_BREAKPOINTS.len() + 1);
+const _: () =
+    assert!(T_COEFFICIENTS.len() == INVERSE_BREAKPOINTS.len() + 1);
+// E(T) and its inverse pass through the origin
+#[cfg(not(feature = "reduced-order"))]
+const _: () = assert!(
+    T_TYPE_E_BELOW_0[0] == 0.0
+        && T_TYPE_E_ABOVE_0[0] == 0.0
+        && T_TYPE_T0[0] == 0.0
+        && T_TYPE_T1[0] == 0.0
+);
+// E(T) and its inverse are continuous at each breakpoint
+#[cfg(not(feature = "reduced-order"))]
+const _: () = {
+    use crate::polyval::{is_continuous, E_STEP_MAX, T_STEP_MAX};
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    assert!(is_continuous(E_COEFFICIENTS, &[b0.0], E_STEP_MAX));
+
+    let [v0] = INVERSE_BREAKPOINTS;
+    assert!(is_continuous(T_COEFFICIENTS, &[v0.0], T_STEP_MAX));
+};
+
+/// Range of temperatures over which E(T) is defined
+pub(crate) const TEMPERATURE_RANGE: (Celsius, Celsius) =
+    (Celsius(-270.0), Celsius(400.0));
+/// Range of E(T) over the full temperature range
+pub(crate) const VOLTAGE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-6.258), Millivolts(20.872));
+/// Range of E(T) over which the inverse function is defined
+pub(crate) const INVERSE_RANGE: (Millivolts, Millivolts) =
+    (Millivolts(-5.603), Millivolts(20.872));
+/// Tolerance for the E(T) range of the inverse function
+#[cfg(feature = "f32")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.005;
+#[cfg(feature = "f64")]
+pub(crate) const INVERSE_TOLERANCE: FP = 0.0005;
+
+/// Values of T at which E(T) switches between coefficient sets, in
+/// increasing order
+pub(crate) const TEMPERATURE_BREAKPOINTS: [Celsius; 1] =
+    [Celsius(0.0)];
+
+/// Values of E(T) at which the inverse function switches between
+/// coefficient sets, in increasing order
+pub(crate) const INVERSE_BREAKPOINTS: [Millivolts; 1] =
+    [Millivolts(0.0)];
+
+/// Temperature at which E(T) is zero
+pub(crate) const ZERO_VOLTAGE_TEMPERATURE: Celsius = Celsius(0.0);
+
+/// E(T) at the default reference junction temperature of 25ºC
+pub(crate) const DEFAULT_REFERENCE_POTENTIAL: Millivolts = e(Celsius(25.0));
+
+/// Values of E(T) from the NIST ITS-90 tables, for the self test
+pub(crate) const ANCHORS: &[(Celsius, Millivolts)] = &[
+    (Celsius(-100.0), Millivolts(-3.379)),
+    (Celsius(200.0), Millivolts(9.288)),
+];
+
+/// Length of the lookup table
+#[cfg(feature = "lut")]
+const TABLE_LEN: usize = crate::lut::table_len(TEMPERATURE_RANGE);
+/// Lookup table of E(T)
+#[cfg(feature = "lut")]
+pub(crate) static TABLE: crate::lut::Table<TABLE_LEN> =
+    lookup_table!(TEMPERATURE_RANGE, TABLE_LEN, |t| e(Celsius(t)).0);
+
 /// Evaluate E(T) for a T-Type thermocouple in the range -270ºC to
 /// 400ºC, where T is in Celsius and E(T) is in millivolts.
-pub fn e(t: Celsius) -> Millivolts {
-    let t = t.0;
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t >= -270.0);
+pub const fn e(t: Celsius) -> Millivolts {
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(t <= 400.0);
+    {
+        let (min, max) = TEMPERATURE_RANGE;
+        assert!(t.0 >= min.0 && t.0 <= max.0);
+    }
 
-    let e = match t > 0.0 {
+    e_unchecked(t)
+}
+
+/// Evaluate E(T) for a T-Type thermocouple, without checking that T is
+/// within the range of the reference function.
+pub(crate) const fn e_unchecked(t: Celsius) -> Millivolts {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let e = match t > b0.0 {
         false => {
             // -270ºC -> 0ºC
-            const C: [FP; 15] = T_TYPE_E_BELOW_0;
+            let c = T_TYPE_E_BELOW_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
-                + C[9] * t * t * t * t * t * t * t * t * t
-                + C[10] * t * t * t * t * t * t * t * t * t * t
-                + C[11] * t * t * t * t * t * t * t * t * t * t * t
-                + C[12] * t * t * t * t * t * t * t * t * t * t * t * t
-                + C[13]
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                + C[14]
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
-                    * t
+            polyval(c, t)
         }
         _ => {
             // 0ºC -> 400ºC
-            const C: [FP; 9] = T_TYPE_E_ABOVE_0;
+            let c = T_TYPE_E_ABOVE_0;
 
             // Power Series
-            C[0] + C[1] * t
-                + C[2] * t * t
-                + C[3] * t * t * t
-                + C[4] * t * t * t * t
-                + C[5] * t * t * t * t * t
-                + C[6] * t * t * t * t * t * t
-                + C[7] * t * t * t * t * t * t * t
-                + C[8] * t * t * t * t * t * t * t * t
+            polyval(c, t)
         }
     };
 
     Millivolts(e)
 }
 
+/// Evaluate the Seebeck coefficient dE/dT for a T-Type thermocouple in
+/// the range -270ºC to 400ºC, where T is in Celsius and dE/dT is in
+/// microvolts per degree Celsius.
+pub fn seebeck_coefficient(t: Celsius) -> FP {
+    #[cfg(not(any(feature = "extrapolate")))]
+    assert!(t >= TEMPERATURE_RANGE.0 && t <= TEMPERATURE_RANGE.1);
+
+    e_and_seebeck_unchecked(t).1
+}
+
+/// Evaluate E(T) and the Seebeck coefficient dE/dT together for a
+/// T-Type thermocouple, without checking that T is within the range of
+/// the reference function. E(T) is in millivolts and dE/dT is in
+/// microvolts per degree Celsius.
+pub(crate) fn e_and_seebeck_unchecked(t: Celsius) -> (Millivolts, FP) {
+    let t = t.0;
+
+    let [b0] = TEMPERATURE_BREAKPOINTS;
+    let (e, de_dt) = match t > b0.0 {
+        false => polyval_with_derivative(T_TYPE_E_BELOW_0, t),
+        _ => polyval_with_derivative(T_TYPE_E_ABOVE_0, t),
+    };
+
+    (Millivolts(e), 1000.0 * de_dt) // µV/ºC
+}
+
+/// Evaluate the permissible deviation for a T-Type thermocouple of
+/// the given IEC 60584-1 tolerance class at temperature T, where T is
+/// in Celsius. Returns `None` if the class is not defined at T.
+pub fn tolerance(t: Celsius, class: ToleranceClass) -> Option<Celsius> {
+    let t = t.0;
+
+    let dt = match class {
+        ToleranceClass::One if (-40.0..=350.0).contains(&t) => {
+            deviation(t, 0.5, 0.004)
+        }
+        ToleranceClass::Two if (-40.0..=350.0).contains(&t) => {
+            deviation(t, 1.0, 0.0075)
+        }
+        ToleranceClass::Three if (-200.0..=40.0).contains(&t) => {
+            deviation(t, 1.0, 0.015)
+        }
+        _ => return None,
+    };
+
+    Some(Celsius(dt))
+}
+
+/// Range of temperatures over which TX extension wire is defined by
+/// IEC 60584-3
+pub(crate) const EXTENSION_RANGE: Option<(Celsius, Celsius)> =
+    Some((Celsius(-25.0), Celsius(100.0)));
+
+/// Evaluate the permissible deviation for TX extension wire of the given
+/// IEC 60584-3 tolerance class, within its temperature range. Returns
+/// `None` if the class is not defined.
+pub fn extension_tolerance(class: ToleranceClass) -> Option<Celsius> {
+    match class {
+        ToleranceClass::One => Some(Celsius(0.5)),
+        ToleranceClass::Two => Some(Celsius(1.0)),
+        _ => None,
+    }
+}
+
 /// Evaluate T for a T-Type thermocouple given E(T) in the range
 /// -5.603mV to 20.872mV, where T is in Celsius and E(T) is in millivolts.
+///
+/// NIST does not define an inverse function below -200ºC, so E(T) from
+/// -270ºC to -200ºC is out of range even though E(T) is defined there.
 pub fn t(e: Millivolts) -> Celsius {
-    let e = e.0;
-    #[cfg(all(feature = "f32", not(feature = "extrapolate")))]
-    const TOL: FP = 0.005; // Tolerance for E(T) range
-    #[cfg(all(feature = "f64", not(feature = "extrapolate")))]
-    const TOL: FP = 0.0005; // Tolerance for E(T) range
-
     #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e >= -5.603 - TOL);
-    #[cfg(not(any(feature = "extrapolate")))]
-    assert!(e <= 20.872 + TOL);
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)
+        .unwrap();
 
-    let c = match e < 0.0 {
+    t_unchecked(e)
+}
+
+/// Returns the temperature for a T-Type thermocouple with the
+/// reference junction at 0ºC, or an error if E(T) is outside the range
+/// of the inverse function. T is in Celsius and E(T) is in millivolts.
+pub fn celsius_from_millivolts(
+    e: Millivolts,
+) -> Result<Celsius, RangeError> {
+    crate::error::check_range(e, INVERSE_RANGE, INVERSE_TOLERANCE)?;
+
+    Ok(t_unchecked(e))
+}
+
+/// Evaluate T for a T-Type thermocouple given E(T), without checking
+/// that E(T) is within the range of the inverse function.
+pub(crate) fn t_unchecked(e: Millivolts) -> Celsius {
+    let e = e.0;
+
+    let [b0] = INVERSE_BREAKPOINTS;
+    let c = match e < b0.0 {
         true => T_TYPE_T0,
         false => T_TYPE_T1,
     };
 
     // Power Series
-    let ps = c[0]
-        + c[1] * e
-        + c[2] * e * e
-        + c[3] * e * e * e
-        + c[4] * e * e * e * e
-        + c[5] * e * e * e * e * e
-        + c[6] * e * e * e * e * e * e
-        + c[7] * e * e * e * e * e * e * e;
+    let ps = polyval(c, e);
 
     Celsius(ps)
 }
@@ -164,6 +320,10 @@ mod tests {
     use crate::tests::compare;
     use crate::{Celsius, FP};
 
+    /// Worst-case error with the `reduced-order` feature, in Celsius
+    #[cfg(feature = "reduced-order")]
+    const REDUCED_ORDER_ERROR: FP = 0.6;
+
     nist_its_90! {
         // NIST inverse function is only defined over a smaller range
         "../nist/type_t.tab.rs", -270, 400, |t| t >= -200
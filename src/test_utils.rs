@@ -1,24 +1,27 @@
 //! Utilities for running unit tests.
 
-#[allow(unused_macros)]
-
 /// Run a unit test against the NIST ITS-90 Thermocouple Database
+#[allow(unused_macros)]
 macro_rules! nist_its_90 {
     ($tab_filename:expr, $low:expr, $high:expr, $t_defined:expr) => {
         /// Tolerances:
-        #[cfg(any(feature = "f32"))]
+        #[cfg(all(feature = "f32", not(feature = "reduced-order")))]
         const E_ERROR_MAX: FP = 0.1; // ±0.1mV maximum error
-        #[cfg(any(feature = "f32"))]
+        #[cfg(all(feature = "f32", not(feature = "reduced-order")))]
         const T_ERROR_MAX: FP = 0.25; // ±0.25ºC maximum error
 
-        #[cfg(any(feature = "f64"))]
+        #[cfg(all(feature = "f64", not(feature = "reduced-order")))]
         /// The maximum error should be ±0.5µV, so that the results
         /// matches the ITS-90 tables exactly
         const E_ERROR_MAX: FP = 0.0005; // ±0.5µV maximum error
-        #[cfg(any(feature = "f64"))]
+        #[cfg(all(feature = "f64", not(feature = "reduced-order")))]
         const T_ERROR_MAX: FP = 0.05; // ±0.05ºC maximum error
+        #[cfg(all(feature = "f64", not(feature = "reduced-order")))]
+        const S_ERROR_MAX: FP = 0.001; // ±0.001µV/ºC maximum error
 
         #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        #[allow(clippy::approx_constant)] // NIST table values
         fn test_e() {
             let nist_tab_e = include!($tab_filename);
 
@@ -35,6 +38,8 @@ macro_rules! nist_its_90 {
         }
 
         #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        #[allow(clippy::approx_constant)] // NIST table values
         fn test_t() {
             let nist_tab_e = include!($tab_filename);
 
@@ -57,6 +62,221 @@ macro_rules! nist_its_90 {
             }
         }
 
+        #[test]
+        #[cfg(all(feature = "f64", not(feature = "reduced-order")))]
+        #[allow(clippy::approx_constant)] // NIST table values
+        fn test_t_refined() {
+            /// Refinement should reach the floating point precision
+            const T_REFINED_ERROR_MAX: FP = 1e-6;
+
+            let nist_tab_e = include!($tab_filename);
+
+            for (t, _) in ($low..).zip(nist_tab_e.iter()) {
+                if ($t_defined)(t) {
+                    let e_calc = super::e(Celsius(t as FP));
+
+                    let t_calc = crate::newton::refine(
+                        e_calc,
+                        super::t(e_calc),
+                        super::e_and_seebeck_unchecked,
+                    );
+
+                    compare(t as FP, t_calc.0, T_REFINED_ERROR_MAX);
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_round_trip_voltage() {
+            /// Number of voltages to sample across the inverse range
+            const STEPS: usize = 1000;
+
+            let (e_min, e_max) = super::INVERSE_RANGE;
+            let (t_min, t_max) = super::TEMPERATURE_RANGE;
+            let mut e_error_max: FP = 0.0;
+
+            for i in 0..=STEPS {
+                let e = e_min.0
+                    + (e_max.0 - e_min.0) * (i as FP) / (STEPS as FP);
+                let t_calc = super::t(super::Millivolts(e));
+                let e_calc = super::e_unchecked(t_calc);
+                e_error_max = e_error_max.max((e_calc.0 - e).abs());
+
+                // Error in E(T) as an error in temperature
+                let s = super::seebeck_coefficient(
+                    t_calc.clamp(t_min, t_max),
+                ) / 1000.0;
+                // NIST quotes errors slightly over T_ERROR_MAX for
+                // some inverse functions, for example up to 0.06ºC for
+                // K-Type above 500ºC
+                compare(e / s, e_calc.0 / s, 2.0 * T_ERROR_MAX);
+            }
+
+            println!("Maximum round trip error {}mV", e_error_max);
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_temperature_breakpoints() {
+            /// Offset either side of each breakpoint, in Celsius
+            const H: FP = 1e-3;
+
+            let breakpoints = super::TEMPERATURE_BREAKPOINTS;
+            assert_eq!(
+                super::E_COEFFICIENTS.len(),
+                breakpoints.len() + 1
+            );
+
+            // The polynomials either side of each breakpoint should
+            // agree
+            for t in breakpoints.iter() {
+                let below = super::e_unchecked(Celsius(t.0 - H));
+                let above = super::e_unchecked(Celsius(t.0 + H));
+
+                compare(below.0, above.0, E_ERROR_MAX);
+            }
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_inverse_breakpoints() {
+            let eval = |c: &[FP], e: FP| {
+                c.iter().rev().fold(0.0, |acc, c| acc * e + c)
+            };
+            let breakpoints = super::INVERSE_BREAKPOINTS;
+            assert_eq!(
+                super::T_COEFFICIENTS.len(),
+                breakpoints.len() + 1
+            );
+
+            // The polynomials either side of each breakpoint should
+            // agree, within the accuracy of the NIST inverse functions
+            for (i, e) in breakpoints.iter().enumerate() {
+                let below = eval(super::T_COEFFICIENTS[i], e.0);
+                let above = eval(super::T_COEFFICIENTS[i + 1], e.0);
+
+                compare(below, above, 2.0 * T_ERROR_MAX);
+            }
+        }
+
+        #[test]
+        #[cfg(all(feature = "f64", not(feature = "reduced-order")))]
+        fn test_seebeck_coefficient() {
+            // Compare against a central difference of E(T), in µV/ºC.
+            // Sample points are offset from whole degrees so that the
+            // difference never straddles a breakpoint between
+            // polynomials.
+            const H: FP = 0.1;
+
+            for t in $low..$high {
+                let t = (t as FP) + 0.3;
+                let e_low = super::e(Celsius(t - H)).0;
+                let e_high = super::e(Celsius(t + H)).0;
+                let de_dt = 1000.0 * (e_high - e_low) / (2.0 * H);
+
+                compare(
+                    de_dt,
+                    super::seebeck_coefficient(Celsius(t)),
+                    S_ERROR_MAX,
+                );
+            }
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_default_reference_potential() {
+            let e_calc = super::e(Celsius(25.0));
+
+            compare(
+                super::DEFAULT_REFERENCE_POTENTIAL.0,
+                e_calc.0,
+                E_ERROR_MAX,
+            );
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_zero_voltage_temperature() {
+            let e_calc = super::e(super::ZERO_VOLTAGE_TEMPERATURE);
+
+            compare(0.0, e_calc.0, E_ERROR_MAX);
+        }
+
+        #[test]
+        #[cfg(not(feature = "reduced-order"))]
+        fn test_voltage_range() {
+            let (t_min, t_max) = super::TEMPERATURE_RANGE;
+            let (e_min, e_max) = super::VOLTAGE_RANGE;
+
+            compare(e_min.0, super::e(t_min).0, E_ERROR_MAX);
+            compare(e_max.0, super::e(t_max).0, E_ERROR_MAX);
+        }
+
+        #[test]
+        #[cfg(feature = "reduced-order")]
+        #[allow(clippy::approx_constant)] // NIST table values
+        fn test_reduced_order() {
+            let nist_tab_e = include!($tab_filename);
+
+            for (t, e) in ($low..).zip(nist_tab_e.iter()) {
+                // The error bound applies over the range of the NIST
+                // inverse function
+                if ($t_defined)(t) {
+                    let t_tab = Celsius(t as FP);
+                    let e_calc = super::e(t_tab);
+
+                    // Error in E(T) as an error in temperature
+                    let s = super::seebeck_coefficient(t_tab) / 1000.0;
+                    compare(*e / s, e_calc.0 / s, REDUCED_ORDER_ERROR);
+
+                    let t_calc =
+                        super::t_unchecked(super::Millivolts(*e));
+                    compare(t as FP, t_calc.0, REDUCED_ORDER_ERROR);
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "lut")]
+        #[allow(clippy::approx_constant)] // NIST table values
+        fn test_lookup_table() {
+            /// Interpolation is accurate to about 1ºC
+            const T_LUT_ERROR_MAX: FP = 1.0;
+
+            let nist_tab_e = include!($tab_filename);
+
+            for (t, _) in ($low..).zip(nist_tab_e.iter()) {
+                // Only as accurate over the range of the NIST inverse
+                // function
+                if ($t_defined)(t) {
+                    let t_tab = Celsius(t as FP);
+                    let e_lut = super::TABLE.e(t_tab);
+                    let e_calc = super::e(t_tab);
+
+                    // Error in E(T) as an error in temperature
+                    let s = super::seebeck_coefficient(t_tab) / 1000.0;
+                    compare(e_calc.0 / s, e_lut.0 / s, T_LUT_ERROR_MAX);
+
+                    let t_lut = super::TABLE.t(e_calc);
+                    compare(t as FP, t_lut.0, T_LUT_ERROR_MAX);
+                }
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "extrapolate")]
+        fn test_e_extreme() {
+            // Overflow to infinity is acceptable far outside the
+            // range, but not NaN
+            for t in &[-1e20, -1e4, 2.0 * ($high as FP), 1e4, 1e20] {
+                let e_calc = super::e(Celsius(*t));
+                println!("{:?}: {:?}", t, e_calc);
+
+                assert!(!e_calc.0.is_nan());
+            }
+        }
+
         #[test]
         #[should_panic]
         #[cfg(not(any(feature = "extrapolate")))]
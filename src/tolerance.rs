@@ -0,0 +1,25 @@
+//! Tolerance classes for thermocouples.
+
+use crate::FP;
+
+/// Tolerance class as defined by IEC 60584-1
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ToleranceClass {
+    /// Class 1
+    One,
+    /// Class 2
+    Two,
+    /// Class 3
+    Three,
+}
+
+/// Permissible deviation, which is the greater of a fixed deviation in
+/// Celsius and a fraction of the magnitude of the temperature
+pub(crate) fn deviation(t: FP, fixed: FP, fraction: FP) -> FP {
+    let proportional = fraction * if t < 0.0 { -t } else { t };
+
+    match proportional > fixed {
+        true => proportional,
+        false => fixed,
+    }
+}
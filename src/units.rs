@@ -1,17 +1,90 @@
 //! Units for thermocouple operations.
 
+use crate::error::BelowAbsoluteZeroError;
+use crate::rounding::{self, RoundingMode};
 use crate::FP;
+use core::cmp::Ordering;
 use core::fmt;
-use core::ops::{Add, Sub};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+#[cfg(feature = "use_serde")]
+use serde::{Deserialize, Serialize};
 
 macro_rules! unit {
-    ($($TYPE:ident, $type:ident => $format:expr, $doc:expr;)*) => {
+    ($($TYPE:ident, $type:ident =>
+       $precision:expr, $suffix:expr, $doc:expr;)*) => {
         $(
             #[doc=$doc]
-            #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+            #[cfg_attr(feature = "use_serde", derive(Serialize, Deserialize))]
             #[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
             pub struct $TYPE(pub FP);
 
+            impl $TYPE {
+                /// Returns true if `self` and `other` differ by no
+                /// more than `tol`
+                pub fn approx_eq(&self, other: &Self, tol: FP) -> bool {
+                    let difference = self.0 - other.0;
+
+                    difference <= tol && -difference <= tol
+                }
+                /// Returns true if `self` lies between `min` and `max`
+                /// inclusive
+                pub fn is_within(self, min: Self, max: Self) -> bool {
+                    self >= min && self <= max
+                }
+                /// Restricts `self` to lie between `min` and `max`
+                /// inclusive
+                pub fn clamp(self, min: Self, max: Self) -> Self {
+                    debug_assert!(min <= max);
+
+                    if self < min {
+                        min
+                    } else if self > max {
+                        max
+                    } else {
+                        self
+                    }
+                }
+                /// Returns the magnitude of `self`
+                pub fn abs(self) -> Self {
+                    match self.0 < 0.0 {
+                        true => -self,
+                        false => self,
+                    }
+                }
+                /// Returns the lesser of `self` and `other`. If one
+                /// of them is NaN, the other is returned.
+                pub fn min(self, other: Self) -> Self {
+                    $TYPE(self.0.min(other.0))
+                }
+                /// Returns the greater of `self` and `other`. If one
+                /// of them is NaN, the other is returned.
+                pub fn max(self, other: Self) -> Self {
+                    $TYPE(self.0.max(other.0))
+                }
+                /// Rounds `self` to the nearest multiple of `step`,
+                /// with halves rounded away from zero. A `step` of
+                /// zero returns `self` unchanged.
+                pub fn round_to(self, step: FP) -> Self {
+                    self.round_to_mode(step, RoundingMode::HalfUp)
+                }
+                /// Rounds `self` to a multiple of `step`, with halves
+                /// handled according to `mode`. A `step` of zero
+                /// returns `self` unchanged.
+                pub fn round_to_mode(
+                    self,
+                    step: FP,
+                    mode: RoundingMode,
+                ) -> Self {
+                    $TYPE(rounding::round(self.0, step, mode))
+                }
+                /// Rounds `self` to the nearest integer, with halves
+                /// rounded to the even integer
+                pub fn round_half_even(self) -> Self {
+                    self.round_to_mode(1.0, RoundingMode::HalfEven)
+                }
+            }
+
             impl Add for $TYPE {
                 type Output = $TYPE;
 
@@ -26,11 +99,80 @@ macro_rules! unit {
                     $TYPE(self.0 - rhs.0)
                 }
             }
+            impl From<FP> for $TYPE {
+                fn from(value: FP) -> $TYPE {
+                    $TYPE(value)
+                }
+            }
+            impl From<$TYPE> for FP {
+                fn from(value: $TYPE) -> FP {
+                    value.0
+                }
+            }
+            impl Neg for $TYPE {
+                type Output = $TYPE;
 
+                fn neg(self) -> $TYPE {
+                    $TYPE(-self.0)
+                }
+            }
+            impl AddAssign for $TYPE {
+                fn add_assign(&mut self, rhs: $TYPE) {
+                    self.0 += rhs.0;
+                }
+            }
+            impl SubAssign for $TYPE {
+                fn sub_assign(&mut self, rhs: $TYPE) {
+                    self.0 -= rhs.0;
+                }
+            }
+            impl Mul<FP> for $TYPE {
+                type Output = $TYPE;
+
+                fn mul(self, rhs: FP) -> $TYPE {
+                    $TYPE(self.0 * rhs)
+                }
+            }
+            impl Mul<$TYPE> for FP {
+                type Output = $TYPE;
+
+                fn mul(self, rhs: $TYPE) -> $TYPE {
+                    $TYPE(self * rhs.0)
+                }
+            }
+            impl Div<FP> for $TYPE {
+                type Output = $TYPE;
+
+                fn div(self, rhs: FP) -> $TYPE {
+                    $TYPE(self.0 / rhs)
+                }
+            }
+
+            impl Sum for $TYPE {
+                fn sum<I: Iterator<Item = $TYPE>>(iter: I) -> $TYPE {
+                    $TYPE(iter.map(|x| x.0).sum())
+                }
+            }
+            impl<'a> Sum<&'a $TYPE> for $TYPE {
+                fn sum<I: Iterator<Item = &'a $TYPE>>(iter: I) -> $TYPE {
+                    $TYPE(iter.map(|x| x.0).sum())
+                }
+            }
 
             impl fmt::Display for $TYPE {
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                    write!(f, $format, self.0)
+                    // Default precision unless one is given
+                    let precision = f.precision().unwrap_or($precision);
+
+                    write!(f, "{:.*}{}", precision, self.0, $suffix)
+                }
+            }
+
+            /// Formats like `Display`, but with full precision
+            #[cfg(feature = "use_defmt")]
+            impl defmt::Format for $TYPE {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(f, "{}{=str}", self.0, $suffix)
                 }
             }
         )*
@@ -55,20 +197,118 @@ macro_rules! unit {
 
 unit! {
     Millivolts, millivolts =>
-        "{:.3}mV", "Unit of electric potential, 1/1000 of the SI
+        3, "mV", "Unit of electric potential, 1/1000 of the SI
+ Base Unit Volt";
+    Microvolts, microvolts =>
+        0, "µV", "Unit of electric potential, 1/1000000 of the SI
  Base Unit Volt";
+    Ohms, ohms =>
+        3, "Ω", "Unit of electrical resistance, the SI Derived Unit";
     Kelvin, kelvin =>
-        "{:.2}K", "Unit of thermodynamic temperature, defined as
+        2, "K", "Unit of thermodynamic temperature, defined as
  the fraction of 1/273.16 of the thermodynamic temperature of the
  triple point of water";
     Celsius, celsius =>
-        "{:.1}ºC", "Unit of thermodynamic temperature";
+        1, "ºC", "Unit of thermodynamic temperature";
     Fahrenheit, fahrenheit =>
-        "{:.1}ºF", "Unit of thermodynamic temperature";
+        1, "ºF", "Unit of thermodynamic temperature";
     Rankine, rankine =>
-        "{:.1}ºRa", "Unit of thermodynamic temperature";
+        1, "ºRa", "Unit of thermodynamic temperature";
     Reaumur, reaumur =>
-        "{:.1}ºRé", "Unit of thermodynamic temperature";
+        1, "ºRé", "Unit of thermodynamic temperature";
+    Newton, newton =>
+        1, "ºN", "Unit of thermodynamic temperature";
+    Delisle, delisle =>
+        1, "ºDe", "Unit of thermodynamic temperature";
+    Romer, romer =>
+        1, "ºRø", "Unit of thermodynamic temperature";
+}
+
+impl Kelvin {
+    /// Absolute zero
+    pub const ZERO: Kelvin = Kelvin(0.0);
+
+    /// Returns a thermodynamic temperature, or an error if it is below
+    /// absolute zero or NaN.
+    pub fn try_new(t: FP) -> Result<Kelvin, BelowAbsoluteZeroError> {
+        // NaN fails the comparison, so is rejected too
+        match t >= 0.0 {
+            true => Ok(Kelvin(t)),
+            false => Err(BelowAbsoluteZeroError { value: Kelvin(t) }),
+        }
+    }
+}
+impl Celsius {
+    /// Absolute zero
+    pub const ABSOLUTE_ZERO: Celsius = Celsius(-273.15);
+
+    /// Returns true if the temperature is at or above absolute zero.
+    /// Returns false for NaN.
+    pub fn is_physical(&self) -> bool {
+        *self >= Celsius::ABSOLUTE_ZERO
+    }
+    /// Converts a temperature to Celsius, or returns an error if it is
+    /// below absolute zero or NaN. The infallible `From` conversions will
+    /// happily return a temperature below absolute zero.
+    pub fn checked_from<T>(
+        t: T,
+    ) -> Result<Celsius, BelowAbsoluteZeroError>
+    where
+        T: Into<Celsius>,
+    {
+        let t = t.into();
+
+        Kelvin::try_new(Kelvin::from(t).0).map(|_| t)
+    }
+    /// Returns the temperature in integer milli-degrees Celsius,
+    /// rounded to the nearest milli-degree. Halves are rounded away
+    /// from zero, and out of range values saturate. Returns `None` if
+    /// the temperature is NaN.
+    pub fn millidegrees(self) -> Option<i32> {
+        if self.0.is_nan() {
+            return None;
+        }
+        let m = self.0 * 1000.0;
+
+        match m < 0.0 {
+            true => Some((m - 0.5) as i32),
+            false => Some((m + 0.5) as i32),
+        }
+    }
+}
+
+/// A unit of temperature on any scale, for generic code
+pub trait Temperature: Into<Celsius> + From<Celsius> + Copy {
+    /// Returns this temperature in Celsius
+    fn as_celsius(self) -> Celsius {
+        self.into()
+    }
+    /// Compares this temperature with one on any scale, by way of
+    /// Celsius. Returns `None` if either temperature is NaN.
+    fn compare_temperature<T: Temperature>(
+        self,
+        other: T,
+    ) -> Option<Ordering> {
+        self.as_celsius().partial_cmp(&other.as_celsius())
+    }
+}
+
+impl Temperature for Celsius {}
+impl Temperature for Kelvin {}
+impl Temperature for Fahrenheit {}
+impl Temperature for Rankine {}
+impl Temperature for Reaumur {}
+impl Temperature for Newton {}
+impl Temperature for Delisle {}
+impl Temperature for Romer {}
+
+/// Converts a temperature between any two scales, by way of Celsius
+pub fn convert<A, B>(a: A) -> B
+where
+    A: Into<Celsius>,
+    Celsius: Into<B>,
+{
+    a.into().into()
 }
 
 // Unit conversions
@@ -92,6 +332,21 @@ impl From<Reaumur> for Celsius {
         Celsius(t.0 * 1.25)
     }
 }
+impl From<Newton> for Celsius {
+    fn from(t: Newton) -> Celsius {
+        Celsius(t.0 * 100.0 / 33.0)
+    }
+}
+impl From<Delisle> for Celsius {
+    fn from(t: Delisle) -> Celsius {
+        Celsius(100.0 - t.0 * 2.0 / 3.0)
+    }
+}
+impl From<Romer> for Celsius {
+    fn from(t: Romer) -> Celsius {
+        Celsius((t.0 - 7.5) * 40.0 / 21.0)
+    }
+}
 impl From<Celsius> for Kelvin {
     fn from(t: Celsius) -> Kelvin {
         Kelvin(t.0 + 273.15)
@@ -109,6 +364,394 @@ impl From<Celsius> for Rankine {
 }
 impl From<Celsius> for Reaumur {
     fn from(t: Celsius) -> Reaumur {
-        Reaumur(t.0 * 0.8)
+        Reaumur(t.0 / 1.25) // Same exact constant as the inverse
+    }
+}
+impl From<Celsius> for Newton {
+    fn from(t: Celsius) -> Newton {
+        Newton(t.0 * 33.0 / 100.0)
+    }
+}
+impl From<Celsius> for Delisle {
+    fn from(t: Celsius) -> Delisle {
+        Delisle((100.0 - t.0) * 1.5)
+    }
+}
+impl From<Celsius> for Romer {
+    fn from(t: Celsius) -> Romer {
+        Romer(t.0 * 21.0 / 40.0 + 7.5)
+    }
+}
+
+impl From<Microvolts> for Millivolts {
+    fn from(e: Microvolts) -> Millivolts {
+        Millivolts(e.0 / 1000.0)
+    }
+}
+impl From<Millivolts> for Microvolts {
+    fn from(e: Millivolts) -> Microvolts {
+        Microvolts(e.0 * 1000.0)
+    }
+}
+
+// Conversions to and from `uom` quantities
+#[cfg(all(feature = "use_uom", feature = "f32"))]
+use uom::si::f32 as si;
+#[cfg(all(feature = "use_uom", feature = "f64"))]
+use uom::si::f64 as si;
+#[cfg(feature = "use_uom")]
+use uom::si::{
+    electric_potential::millivolt, thermodynamic_temperature,
+};
+
+#[cfg(feature = "use_uom")]
+impl From<Celsius> for si::ThermodynamicTemperature {
+    fn from(t: Celsius) -> si::ThermodynamicTemperature {
+        si::ThermodynamicTemperature::new::<
+            thermodynamic_temperature::degree_celsius,
+        >(t.0)
+    }
+}
+#[cfg(feature = "use_uom")]
+impl From<si::ThermodynamicTemperature> for Celsius {
+    fn from(t: si::ThermodynamicTemperature) -> Celsius {
+        Celsius(t.get::<thermodynamic_temperature::degree_celsius>())
+    }
+}
+#[cfg(feature = "use_uom")]
+impl From<Kelvin> for si::ThermodynamicTemperature {
+    fn from(t: Kelvin) -> si::ThermodynamicTemperature {
+        si::ThermodynamicTemperature::new::<
+            thermodynamic_temperature::kelvin,
+        >(t.0)
+    }
+}
+#[cfg(feature = "use_uom")]
+impl From<si::ThermodynamicTemperature> for Kelvin {
+    fn from(t: si::ThermodynamicTemperature) -> Kelvin {
+        Kelvin(t.get::<thermodynamic_temperature::kelvin>())
+    }
+}
+#[cfg(feature = "use_uom")]
+impl From<Millivolts> for si::ElectricPotential {
+    fn from(e: Millivolts) -> si::ElectricPotential {
+        si::ElectricPotential::new::<millivolt>(e.0)
+    }
+}
+#[cfg(feature = "use_uom")]
+impl From<si::ElectricPotential> for Millivolts {
+    fn from(e: si::ElectricPotential) -> Millivolts {
+        Millivolts(e.get::<millivolt>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::compare;
+
+    #[test]
+    /// Test generic code over the temperature scales
+    fn temperature_trait() {
+        fn above_freezing<T: Temperature>(t: T) -> bool {
+            t.as_celsius() > Celsius(0.0)
+        }
+
+        assert!(above_freezing(Kelvin(274.0)));
+        assert!(!above_freezing(Kelvin(272.0)));
+        assert!(above_freezing(Fahrenheit(33.0)));
+        assert!(!above_freezing(Fahrenheit(31.0)));
+    }
+
+    #[test]
+    /// Test comparing temperatures on different scales
+    fn compare_temperature() {
+        let boiling = Celsius(100.0);
+
+        assert_eq!(
+            boiling.compare_temperature(Fahrenheit(212.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            boiling.compare_temperature(Kelvin(300.0)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(boiling.compare_temperature(Kelvin(FP::NAN)), None);
+    }
+
+    #[test]
+    /// Test the historical scales at the boiling point of water
+    fn historical_scales() {
+        let boiling = Celsius(100.0);
+
+        compare(Newton::from(boiling).0, 33.0, 1e-4);
+        compare(Delisle::from(boiling).0, 0.0, 1e-4);
+        compare(Romer::from(boiling).0, 60.0, 1e-4);
+
+        compare(Celsius::from(Newton(33.0)).0, 100.0, 1e-4);
+        compare(Celsius::from(Delisle(150.0)).0, 0.0, 1e-4);
+        compare(Celsius::from(Romer(7.5)).0, 0.0, 1e-4);
+    }
+
+    #[test]
+    /// Test every scale recovers the temperature after converting to
+    /// and from Celsius, and through a chain of scales
+    fn round_trip_scales() {
+        #[cfg(feature = "f32")]
+        const TOLERANCE: FP = 1e-3;
+        #[cfg(feature = "f64")]
+        const TOLERANCE: FP = 1e-9;
+
+        fn round_trip<T: Temperature>(t: Celsius) -> Celsius {
+            T::from(t).as_celsius()
+        }
+
+        for t in
+            &[-273.15, -200.0, -40.0, 0.0, 0.1, 25.0, 1000.0, 1820.0]
+        {
+            let t = Celsius(*t);
+
+            compare(round_trip::<Kelvin>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Fahrenheit>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Rankine>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Reaumur>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Newton>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Delisle>(t).0, t.0, TOLERANCE);
+            compare(round_trip::<Romer>(t).0, t.0, TOLERANCE);
+
+            let k: Kelvin = convert(t);
+            let f: Fahrenheit = convert(k);
+            let r: Rankine = convert(f);
+            let re: Reaumur = convert(r);
+            compare(Celsius::from(re).0, t.0, TOLERANCE);
+        }
+    }
+
+    #[test]
+    /// Test converting directly between two non-Celsius scales
+    fn convert_scales() {
+        let k: Kelvin = convert::<Fahrenheit, Kelvin>(Fahrenheit(32.0));
+        compare(k.0, 273.15, 1e-4);
+
+        let f: Fahrenheit = convert(Kelvin(373.15));
+        compare(f.0, 212.0, 1e-4);
+    }
+
+    #[test]
+    /// Test converting between millivolts and microvolts
+    fn microvolts() {
+        compare(Millivolts::from(Microvolts(1100.0)).0, 1.1, 1e-6);
+        compare(Microvolts::from(Millivolts(1.1)).0, 1100.0, 1e-6);
+        assert_eq!(1100.0.microvolts(), Microvolts(1100.0));
+        assert_eq!(format!("{}", Microvolts(1100.4)), "1100µV");
+    }
+
+    #[test]
+    /// Test scaling and accumulating units
+    fn arithmetic() {
+        assert_eq!(Millivolts(1.0) * 2.0, Millivolts(2.0));
+        assert_eq!(2.0 * Millivolts(1.0), Millivolts(2.0));
+        assert_eq!(Celsius(30.0) / 2.0, Celsius(15.0));
+
+        let mut total = Celsius(10.0);
+        total += Celsius(20.0);
+        assert_eq!(total, Celsius(30.0));
+        total -= Celsius(5.0);
+        assert_eq!(total, Celsius(25.0));
+    }
+
+    #[test]
+    /// Test tracking the extremes of a stream of readings
+    fn min_max() {
+        let readings = [Celsius(21.0), Celsius(19.5), Celsius(23.25)];
+
+        let mut low = readings[0];
+        let mut high = readings[0];
+        for reading in readings.iter() {
+            low = low.min(*reading);
+            high = high.max(*reading);
+        }
+        assert_eq!(low, Celsius(19.5));
+        assert_eq!(high, Celsius(23.25));
+
+        assert_eq!(
+            Millivolts(FP::NAN).max(Millivolts(1.0)),
+            Millivolts(1.0)
+        );
+    }
+
+    #[test]
+    /// Test temperatures below absolute zero are rejected
+    fn below_absolute_zero() {
+        assert!(Kelvin::try_new(-1.0).is_err());
+        assert!(Kelvin::try_new(FP::NAN).is_err());
+        assert_eq!(Kelvin::try_new(0.0), Ok(Kelvin(0.0)));
+
+        assert!(Celsius::checked_from(Kelvin(-1.0)).is_err());
+        assert!(Celsius::checked_from(Celsius(FP::NAN)).is_err());
+        assert!(Celsius::checked_from(Fahrenheit(-500.0)).is_err());
+        assert_eq!(
+            Celsius::checked_from(Kelvin(273.15)),
+            Ok(Celsius(0.0))
+        );
+    }
+
+    #[cfg(feature = "use_uom")]
+    #[test]
+    /// Test round trips through `uom` quantities
+    fn uom_round_trip() {
+        use uom::si::thermodynamic_temperature::kelvin;
+
+        let t: si::ThermodynamicTemperature = Celsius(25.0).into();
+        compare(t.get::<kelvin>(), 298.15, 1e-4);
+        compare(Celsius::from(t).0, 25.0, 1e-4);
+        compare(Kelvin::from(t).0, 298.15, 1e-4);
+
+        let e: si::ElectricPotential = Millivolts(1.1).into();
+        compare(Millivolts::from(e).0, 1.1, 1e-6);
+    }
+
+    #[test]
+    /// Test absolute zero on each scale
+    fn absolute_zero() {
+        assert_eq!(Kelvin::from(Celsius::ABSOLUTE_ZERO), Kelvin::ZERO);
+        assert_eq!(Celsius::from(Kelvin::ZERO), Celsius::ABSOLUTE_ZERO);
+
+        assert!(Celsius::ABSOLUTE_ZERO.is_physical());
+        assert!(!Celsius(-273.2).is_physical());
+        assert!(!Celsius(FP::NAN).is_physical());
+    }
+
+    #[test]
+    /// Test rounding to a display step
+    fn round_to() {
+        assert_eq!(Celsius(24.73).round_to(0.5), Celsius(24.5));
+        assert_eq!(Celsius(24.75).round_to(0.5), Celsius(25.0));
+        assert_eq!(Celsius(-24.73).round_to(0.5), Celsius(-24.5));
+        assert_eq!(Celsius(-24.75).round_to(0.5), Celsius(-25.0));
+        assert_eq!(Celsius(24.73).round_to(0.0), Celsius(24.73));
+    }
+
+    #[test]
+    /// Test the rounding modes at and either side of a half
+    fn round_to_mode() {
+        let half_even = RoundingMode::HalfEven;
+        assert_eq!(
+            Celsius(2.5).round_to_mode(1.0, half_even),
+            Celsius(2.0)
+        );
+        assert_eq!(
+            Celsius(3.5).round_to_mode(1.0, half_even),
+            Celsius(4.0)
+        );
+        assert_eq!(Celsius(-2.5).round_half_even(), Celsius(-2.0));
+        assert_eq!(Celsius(-3.5).round_half_even(), Celsius(-4.0));
+        assert_eq!(Celsius(2.6).round_half_even(), Celsius(3.0));
+        assert_eq!(
+            Celsius(24.75).round_to_mode(0.5, half_even),
+            Celsius(25.0)
+        );
+
+        let half_up = RoundingMode::HalfUp;
+        assert_eq!(
+            Celsius(2.5).round_to_mode(1.0, half_up),
+            Celsius(3.0)
+        );
+
+        let toward_zero = RoundingMode::TowardZero;
+        assert_eq!(
+            Celsius(2.9).round_to_mode(1.0, toward_zero),
+            Celsius(2.0)
+        );
+        assert_eq!(
+            Celsius(-2.9).round_to_mode(1.0, toward_zero),
+            Celsius(-2.0)
+        );
+    }
+
+    #[test]
+    /// Test rounding to integer milli-degrees
+    fn millidegrees() {
+        assert_eq!(Celsius(25.123).millidegrees(), Some(25123));
+        assert_eq!(Celsius(-25.123).millidegrees(), Some(-25123));
+
+        // Halves round away from zero
+        assert_eq!(Celsius(0.0625).millidegrees(), Some(63));
+        assert_eq!(Celsius(-0.0625).millidegrees(), Some(-63));
+
+        assert_eq!(Celsius(1e10).millidegrees(), Some(i32::MAX));
+        assert_eq!(Celsius(FP::NAN).millidegrees(), None);
+    }
+
+    #[test]
+    /// Test formatting honours the precision, if given
+    fn display_precision() {
+        assert_eq!(format!("{}", Celsius(1.23456)), "1.2ºC");
+        assert_eq!(format!("{:.4}", Celsius(1.23456)), "1.2346ºC");
+        assert_eq!(format!("{}", Millivolts(1.23456)), "1.235mV");
+        assert_eq!(format!("{:.0}", Kelvin(300.4)), "300K");
+    }
+
+    #[test]
+    fn from_fp() {
+        assert_eq!(Millivolts::from(1.5), Millivolts(1.5));
+
+        let reading: FP = 21.5;
+        let t: Celsius = reading.into();
+        assert_eq!(t, Celsius(21.5));
+    }
+
+    #[test]
+    fn clamp_is_within() {
+        let (min, max) = (Millivolts(0.0), Millivolts(54.0));
+
+        assert_eq!(Millivolts(60.0).clamp(min, max), max);
+        assert_eq!(Millivolts(-1.0).clamp(min, max), min);
+        assert_eq!(Millivolts(1.1).clamp(min, max), Millivolts(1.1));
+
+        assert!(Millivolts(54.0).is_within(min, max));
+        assert!(!Millivolts(54.1).is_within(min, max));
+        assert!(!Millivolts(FP::NAN).is_within(min, max));
+    }
+
+    #[test]
+    fn neg_abs() {
+        let setpoint = Celsius(20.0);
+        let reading = Celsius(22.5);
+
+        assert_eq!(-(setpoint - reading), Celsius(2.5));
+        assert_eq!((setpoint - reading).abs(), Celsius(2.5));
+        assert_eq!((reading - setpoint).abs(), Celsius(2.5));
+        assert_eq!(Millivolts(-1.0).abs(), Millivolts(1.0));
+    }
+
+    #[test]
+    fn approx_eq() {
+        assert!(Celsius(25.0).approx_eq(&Celsius(25.0001), 0.001));
+        assert!(Celsius(25.0001).approx_eq(&Celsius(25.0), 0.001));
+        assert!(!Celsius(25.0).approx_eq(&Celsius(25.01), 0.001));
+        assert!(
+            !Millivolts(FP::NAN).approx_eq(&Millivolts(FP::NAN), 1.0)
+        );
+    }
+
+    #[test]
+    /// Test summing units from owned and borrowed iterators
+    fn sum() {
+        let samples = [Celsius(10.0), Celsius(20.0)];
+
+        assert_eq!(
+            samples.iter().copied().sum::<Celsius>(),
+            Celsius(30.0)
+        );
+        assert_eq!(samples.iter().sum::<Celsius>(), Celsius(30.0));
+        assert_eq!(
+            samples.iter().sum::<Celsius>() / samples.len() as FP,
+            Celsius(15.0)
+        );
+        assert_eq!(
+            core::iter::empty::<Millivolts>().sum::<Millivolts>(),
+            Millivolts(0.0)
+        );
     }
 }